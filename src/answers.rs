@@ -0,0 +1,167 @@
+//! The manifest of known answers, `answers.toml`.
+//!
+//! Answers are grouped in a table per input name, keyed by day and part:
+//!
+//! ```toml
+//! [checked-in]
+//! 01a = 997899
+//! 01b = 131248694
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::days::Part;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, String>>);
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, std::io::Error),
+    Toml(toml::de::Error),
+    /// An answer that is neither an integer nor a string, at `[input] key`.
+    Value(String, String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, e) => write!(f, "could not read answers from {}: {}", path.display(), e),
+            AnswersError::Toml(e) => write!(f, "invalid answers manifest: {}", e),
+            AnswersError::Value(input, key) =>
+                write!(f, "answer {} for input {} must be an integer or a string", key, input),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+fn key(day: u32, part: Part) -> String {
+    format!("{:02}{}", day, part)
+}
+
+impl Answers {
+    /// The manifest checked in at the crate root.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AnswersError> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path).map_err(|e| AnswersError::Io(path.to_path_buf(), e))?;
+        Self::parse(&s)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, AnswersError> {
+        let table = s.parse::<Table>().map_err(AnswersError::Toml)?;
+        let mut answers = BTreeMap::new();
+        for (input, entries) in table {
+            let entries = match entries {
+                Value::Table(entries) => entries,
+                _ => return Err(AnswersError::Value(input, String::new())),
+            };
+            let mut parsed = BTreeMap::new();
+            for (key, value) in entries {
+                let value = match value {
+                    Value::Integer(i) => i.to_string(),
+                    Value::String(s) => s,
+                    _ => return Err(AnswersError::Value(input, key)),
+                };
+                parsed.insert(key, value);
+            }
+            answers.insert(input, parsed);
+        }
+        Ok(Self(answers))
+    }
+
+    /// The names of the inputs with answers.
+    pub fn inputs(&self) -> impl Iterator<Item=&str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn expected(&self, input: &str, day: u32, part: Part) -> Option<&str> {
+        self.0.get(input)?
+            .get(&key(day, part))
+            .map(String::as_str)
+    }
+
+    pub fn verify(&self, input: &str, day: u32, part: Part, actual: &str) -> Verdict {
+        match self.expected(input, day, part) {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// The result of checking an answer against the manifest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// The answer differs from the expected one, given.
+    Fail(String),
+    /// The manifest has no answer for the input, day and part.
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("pass"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => f.write_str("unknown (no expected answer)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "
+[checked-in]
+01a = 997899
+14b = \"3618217244644\"
+
+[example]
+01a = 514579
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        assert_eq!(Some("997899"), answers.expected("checked-in", 1, Part::A));
+        assert_eq!(Some("3618217244644"), answers.expected("checked-in", 14, Part::B));
+        assert_eq!(Some("514579"), answers.expected("example", 1, Part::A));
+        assert_eq!(None, answers.expected("example", 1, Part::B));
+        assert_eq!(None, answers.expected("other", 1, Part::A));
+        assert_eq!(vec!["checked-in", "example"], answers.inputs().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        assert_eq!(Verdict::Pass, answers.verify("checked-in", 1, Part::A, "997899"));
+        assert_eq!(Verdict::Fail("997899".into()), answers.verify("checked-in", 1, Part::A, "1"));
+        assert_eq!(Verdict::Unknown, answers.verify("checked-in", 2, Part::A, "1"));
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let e = Answers::parse("[checked-in]\n01a = 1.5\n").unwrap_err();
+        assert_eq!("answer 01a for input checked-in must be an integer or a string", e.to_string());
+    }
+
+    #[test]
+    fn test_manifest_covers_every_solver() {
+        let answers = Answers::load(Answers::default_path()).unwrap();
+        for solver in crate::days::solvers() {
+            assert!(answers.expected("checked-in", solver.day, solver.part).is_some(), "{}", solver.name());
+        }
+    }
+}
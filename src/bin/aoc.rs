@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+use std::process::exit;
+
+use adventofcode2020::answers::{Answers, Verdict};
+use adventofcode2020::days::{self, Part, Solver};
+use adventofcode2020::generate;
+use adventofcode2020::input::{Input, Source};
+use adventofcode2020::parallel;
+use adventofcode2020::timing::{Report, Short};
+
+const USAGE: &str = "usage:
+    aoc list
+    aoc run <day> [a|b] [variant] [--input <path|->] [options]
+    aoc run all [options]
+    aoc gen <day> [--size <n>] [--seed <n>]
+
+options:
+    --verify            check answers against the answers manifest
+    --answers <path>    the answers manifest, by default answers.toml in the crate root
+    --name <name>       the name of the input in the manifest, by default `checked-in` or the file stem
+    --time              time parse and solve separately and print a table of medians and minimums
+    --runs <n>          how many times to run each step when timing, by default 10
+    --json <path|->     also write the timings as JSON, to stdout for `-` with the table on stderr;
+                        implies --time
+    --jobs <n>          solve up to n parts at once, by default 1; timings then share the CPU
+    --size <n>          how large an input to generate, by default about the size of the real one
+    --seed <n>          the seed for generating input, by default 0
+
+Input is read from --input (`-` for stdin), else aocNN.txt in $AOC_INPUT_DIR, else the checked in input.";
+
+struct Options {
+    source: Option<Source>,
+    answers: Option<Answers>,
+    name: Option<String>,
+    /// How many times to run each solver when timing.
+    runs: Option<usize>,
+    json: Option<String>,
+    jobs: usize,
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let source = take_option(&mut args, "--input").map(|arg| Source::from_arg(&arg));
+    let answers_path = take_option(&mut args, "--answers");
+    let name = take_option(&mut args, "--name");
+    let verify = take_flag(&mut args, "--verify");
+    let time = take_flag(&mut args, "--time");
+    let runs = take_option(&mut args, "--runs")
+        .map(|n| number(&n, "--runs"))
+        .map(|n| if n > 0 { n as usize } else { usage("--runs must be at least 1") });
+    let json = take_option(&mut args, "--json");
+    let jobs = take_option(&mut args, "--jobs")
+        .map(|n| number(&n, "--jobs"))
+        .map_or(1, |n| if n > 0 { n as usize } else { usage("--jobs must be at least 1") });
+    let size = take_option(&mut args, "--size").map(|n| number(&n, "--size"));
+    let seed = take_option(&mut args, "--seed").map(|n| number(&n, "--seed"));
+    let runs = match (time || runs.is_some() || json.is_some(), runs) {
+        (true, runs) => Some(runs.unwrap_or(10)),
+        (false, _) => None,
+    };
+    let answers = if verify {
+        let path = answers_path.map_or_else(Answers::default_path, Into::into);
+        Some(Answers::load(path).unwrap_or_else(|e| {
+            eprintln!("aoc: {}", e);
+            exit(1)
+        }))
+    } else {
+        None
+    };
+    let options = Options { source, answers, name, runs, json, jobs };
+
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    match args.as_slice() {
+        ["list"] => list(),
+        ["run", "all"] if options.source.is_none() => run(days::solvers().iter(), &options),
+        ["run", "all"] => usage("--input can only be used when running a single day"),
+        ["run", day, rest @ ..] if rest.len() <= 2 => {
+            let day = day.parse::<u32>().unwrap_or_else(|_| usage(&format!("invalid day {:?}", day)));
+            let part = rest.first().map(|p| p.parse::<Part>().unwrap_or_else(|e| usage(&e.to_string())));
+            let variant = rest.get(1).copied();
+            let mut solvers = days::find(day, part, variant).peekable();
+            if solvers.peek().is_none() {
+                usage(&format!("no solver for day {}{}", day, part.map_or(String::new(), |p| p.to_string())));
+            }
+            run(solvers, &options);
+        }
+        ["gen", day] => {
+            let day = day.parse::<u32>().unwrap_or_else(|_| usage(&format!("invalid day {:?}", day)));
+            let size = size.map(|n| n as usize).or_else(|| generate::default_size(day));
+            match size.and_then(|size| generate::generate(day, size, seed.unwrap_or(0))) {
+                Some(input) => print!("{}", input),
+                None => usage(&format!("no generator for day {}", day)),
+            }
+        }
+        _ => usage("")
+    }
+}
+
+fn number(s: &str, option: &str) -> u64 {
+    s.parse().unwrap_or_else(|_| usage(&format!("invalid {} {:?}", option, s)))
+}
+
+/// Removes `name` and the value following it from `args`.
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let i = args.iter().position(|a| a == name)?;
+    if i + 1 == args.len() {
+        usage(&format!("{} needs a value", name));
+    }
+    args.remove(i);
+    Some(args.remove(i))
+}
+
+/// Removes `name` from `args`, returning whether it was there.
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let len = args.len();
+    args.retain(|a| a != name);
+    args.len() != len
+}
+
+fn usage(message: &str) -> ! {
+    if !message.is_empty() {
+        eprintln!("aoc: {}", message);
+    }
+    eprintln!("{}", USAGE);
+    exit(2)
+}
+
+fn list() {
+    for solver in days::solvers() {
+        println!("{:<12} {}", solver.name(), solver.title);
+    }
+}
+
+/// Runs `solvers`, reading each day's input once, and prints their answers in order.
+///
+/// Solvers run on up to `--jobs` threads. One that panics or can not parse its input is reported
+/// and the rest still run, but the exit status is a failure, as it is when verifying and an answer
+/// differs from the manifest. When timing, prints a table row per solver instead, and writes the
+/// JSON report at the end.
+fn run<'a>(solvers: impl Iterator<Item=&'a Solver>, options: &Options) {
+    let solvers = solvers.collect::<Vec<_>>();
+    let mut inputs = BTreeMap::new();
+    for solver in &solvers {
+        inputs.entry(solver.day).or_insert_with(|| {
+            let source = options.source.clone().unwrap_or_else(|| Source::default_for(solver.day));
+            Input::read(solver.day, source).unwrap_or_else(|e| {
+                eprintln!("aoc: day {}: {}", solver.day, e);
+                exit(1)
+            })
+        });
+    }
+
+    let mut report = options.runs.map(Report::new);
+    if report.is_some() {
+        table_line(options, &Report::header());
+    }
+    let mut failed = 0;
+    let mut unknown = 0;
+    let mut passed = 0;
+    let mut broken = 0;
+    let solve = |solver: &&Solver| {
+        let input = &inputs[&solver.day];
+        match options.runs {
+            Some(runs) => solver.time(&input.text, runs).map(|timing| (timing.answer.clone(), Some(timing))),
+            None => solver.solve(&input.text).map(|answer| (answer, None)),
+        }
+    };
+    parallel::map_ordered(&solvers, options.jobs, solve, |i, result| {
+        let solver = solvers[i];
+        let input = &inputs[&solver.day];
+        let (answer, timing) = match result {
+            Ok(Ok(solved)) => solved,
+            Ok(Err(e)) => {
+                eprintln!("{}", e.diagnostic(&input.source));
+                broken += 1;
+                return;
+            }
+            Err(message) => {
+                eprintln!("aoc: {} panicked: {}", solver.name(), message);
+                broken += 1;
+                return;
+            }
+        };
+        let verdict = options.answers.as_ref().map(|answers| {
+            let name = options.name.clone().unwrap_or_else(|| input.name());
+            let verdict = answers.verify(&name, solver.day, solver.part, &answer);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail(_) => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+            verdict
+        });
+        let verdict = verdict.map_or(String::new(), |v| format!(" {}", v));
+        match (timing, &mut report) {
+            (Some(timing), Some(report)) => {
+                table_line(options, &format!("{}{}", Report::row(&solver.name(), &timing), verdict));
+                report.add(solver, timing);
+            }
+            _ => println!("{}: {}{}", solver.name(), answer, verdict),
+        }
+    });
+    if let Some(report) = report {
+        table_line(options, &format!("{:<12} {:>21}", "total", Short(report.total())));
+        write_json(&report, options);
+    }
+    if options.answers.is_some() {
+        eprintln!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    }
+    if broken > 0 {
+        eprintln!("aoc: {} of {} could not be solved", broken, solvers.len());
+    }
+    if failed > 0 || broken > 0 {
+        exit(1);
+    }
+}
+
+/// Prints a line of the timing table, on stderr when `--json -` has stdout for the JSON.
+fn table_line(options: &Options, line: &str) {
+    if options.json.as_deref() == Some("-") {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+/// Writes the timings as JSON where `--json` asked for them.
+fn write_json(report: &Report, options: &Options) {
+    match options.json.as_deref() {
+        Some("-") => print!("{}", report.to_json()),
+        Some(path) => std::fs::write(path, report.to_json()).unwrap_or_else(|e| {
+            eprintln!("aoc: could not write {}: {}", path, e);
+            exit(1)
+        }),
+        None => {}
+    }
+}
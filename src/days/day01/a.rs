@@ -0,0 +1,9 @@
+use crate::solution::Answer;
+
+use super::{find_k_sum, TARGET};
+
+/// The product of the two entries that add up to 2020.
+pub fn solve(numbers: &[u32]) -> Answer<u32> {
+    let pair = find_k_sum(numbers, 2, TARGET).map(|pair| pair.iter().product());
+    Answer::from_option(pair, "no two entries add up to 2020")
+}
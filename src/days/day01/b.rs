@@ -0,0 +1,9 @@
+use crate::solution::Answer;
+
+use super::{find_k_sum, TARGET};
+
+/// The product of the three entries that add up to 2020.
+pub fn solve(numbers: &[u32]) -> Answer<u32> {
+    let triple = find_k_sum(numbers, 3, TARGET).map(|triple| triple.iter().product());
+    Answer::from_option(triple, "no three entries add up to 2020")
+}
@@ -0,0 +1,472 @@
+use std::collections::BTreeMap;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::process::exit;
+
+use crate::days::Part;
+use crate::input;
+use crate::solution::{number, parse_lines, Answer, ParseError, Solution};
+
+pub mod a;
+pub mod b;
+
+/// What the expense report entries must add up to.
+pub const TARGET: u32 = 2020;
+
+/// Some `k` of `numbers`, at distinct positions, that add up to `target`, in ascending order.
+pub fn find_k_sum(numbers: &[u32], k: usize, target: u32) -> Option<Vec<u32>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let mut tuple = None;
+    k_sum(&sorted, k, target as u64, &mut Vec::with_capacity(k), &mut |t| {
+        tuple = Some(t.to_vec());
+        true
+    });
+    tuple
+}
+
+/// Every distinct ascending tuple of `k` of `numbers`, at distinct positions, that add up to
+/// `target`, in lexicographic order. A value can appear in a tuple as many times as in `numbers`.
+pub fn find_all_k_sums(numbers: &[u32], k: usize, target: u32) -> Vec<Vec<u32>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    let mut tuples = Vec::new();
+    k_sum(&sorted, k, target as u64, &mut Vec::with_capacity(k), &mut |t| {
+        tuples.push(t.to_vec());
+        false
+    });
+    tuples
+}
+
+/// Every combination of `k` positions in `numbers` whose values add up to `target`, as ascending
+/// positions. Unlike [`find_all_k_sums`], equal values at different positions make different
+/// combinations, so `[1010, 1010, 1010]` has three pairs adding up to 2020.
+pub fn k_sums(numbers: &[u32], k: usize, target: u32) -> KSums<'_> {
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| numbers[i]);
+    KSums { numbers, order, k, target: target as u64, prefix: Vec::with_capacity(k), last: 0..0, started: false }
+}
+
+/// The iterator returned by [`k_sums`].
+///
+/// Positions are visited in order of their values. All but the last value of a combination are
+/// chosen one at a time, pruned like [`find_k_sum`]; the last is every position holding exactly
+/// the value still missing, found by binary search.
+#[derive(Clone, Debug)]
+pub struct KSums<'a> {
+    numbers: &'a [u32],
+    /// Positions in `numbers`, in order of their values.
+    order: Vec<usize>,
+    k: usize,
+    target: u64,
+    /// Indices into `order` of all but the last value of the current combination.
+    prefix: Vec<usize>,
+    /// Indices into `order` of the values completing the current prefix.
+    last: Range<usize>,
+    started: bool,
+}
+
+impl KSums<'_> {
+    fn value(&self, i: usize) -> u64 {
+        self.numbers[self.order[i]] as u64
+    }
+
+    /// Moves on to the next prefix that could be completed, setting `last` to what completes it.
+    fn advance(&mut self) -> bool {
+        let mut start = if self.started {
+            match self.prefix.pop() {
+                Some(i) => i + 1,
+                None => return false,
+            }
+        } else {
+            self.started = true;
+            0
+        };
+        let largest = self.order.len().checked_sub(1).map_or(0, |i| self.value(i));
+        loop {
+            let sum = self.prefix.iter().map(|&i| self.value(i)).sum::<u64>();
+            let missing = self.target.checked_sub(sum);
+            let remaining = self.k - self.prefix.len();
+            if remaining == 1 {
+                let (from, missing) = (start, missing.unwrap_or(u64::MAX));
+                let rest = &self.order[from..];
+                let low = rest.partition_point(|&i| (self.numbers[i] as u64) < missing);
+                let high = rest.partition_point(|&i| self.numbers[i] as u64 <= missing);
+                self.last = from + low..from + high;
+                return true;
+            }
+            let fits = start + remaining <= self.order.len()
+                && missing.is_some_and(|m| self.value(start).saturating_mul(remaining as u64) <= m);
+            if !fits {
+                match self.prefix.pop() {
+                    Some(i) => start = i + 1,
+                    None => return false,
+                }
+            } else if self.value(start) + largest.saturating_mul(remaining as u64 - 1) < missing.unwrap() {
+                start += 1;
+            } else {
+                self.prefix.push(start);
+                start += 1;
+            }
+        }
+    }
+}
+
+impl Iterator for KSums<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.k == 0 {
+            let first = !self.started;
+            self.started = true;
+            return if first && self.target == 0 { Some(Vec::new()) } else { None };
+        }
+        loop {
+            if let Some(last) = self.last.next() {
+                let mut positions = self.prefix.iter()
+                    .chain(std::iter::once(&last))
+                    .map(|&i| self.order[i])
+                    .collect::<Vec<_>>();
+                positions.sort_unstable();
+                return Some(positions);
+            }
+            if !self.advance() {
+                return None;
+            }
+        }
+    }
+}
+
+impl FusedIterator for KSums<'_> {}
+
+/// How many combinations [`k_sums`] would give, counted from how often each value appears rather
+/// than by listing them.
+pub fn count_k_sums(numbers: &[u32], k: usize, target: u32) -> u128 {
+    let mut frequencies = BTreeMap::new();
+    for &n in numbers {
+        *frequencies.entry(n as u64).or_insert(0u64) += 1;
+    }
+    let frequencies = frequencies.into_iter().collect::<Vec<_>>();
+    count(&frequencies, k, target as u64)
+}
+
+/// The number of ways to pick `k` items adding up to `target` from `frequencies`, distinct values
+/// in ascending order each with how many items have it.
+fn count(frequencies: &[(u64, u64)], k: usize, target: u64) -> u128 {
+    match k {
+        0 => (target == 0) as u128,
+        1 => frequencies.binary_search_by_key(&target, |&(v, _)| v)
+            .map_or(0, |i| frequencies[i].1 as u128),
+        _ => {
+            let largest = frequencies.last().map_or(0, |&(v, _)| v);
+            let mut total = 0;
+            for (i, &(value, frequency)) in frequencies.iter().enumerate() {
+                if value.saturating_mul(k as u64) > target {
+                    break;
+                }
+                if value.saturating_add(largest.saturating_mul(k as u64 - 1)) < target {
+                    continue;
+                }
+                for m in 1..=frequency.min(k as u64) {
+                    if value * m > target {
+                        break;
+                    }
+                    total += binomial(frequency, m) * count(&frequencies[i + 1..], k - m as usize, target - value * m);
+                }
+            }
+            total
+        }
+    }
+}
+
+/// The number of ways to choose `k` of `n`.
+fn binomial(n: u64, k: u64) -> u128 {
+    if k > n {
+        return 0;
+    }
+    (0..k.min(n - k)).fold(1u128, |c, i| c * (n - i) as u128 / (i + 1) as u128)
+}
+
+/// Hands each distinct `k`-tuple of `sorted` adding up to `target`, after `prefix`, to `found`
+/// until it returns true, and returns whether it did.
+///
+/// Pairs are found with two pointers closing in from both ends. Longer tuples fix their smallest
+/// value and recurse, skipping values too small to reach the target even with the largest ones
+/// after them, and stopping once `k` of the value would exceed it.
+fn k_sum(sorted: &[u32], k: usize, target: u64, prefix: &mut Vec<u32>, found: &mut dyn FnMut(&[u32]) -> bool) -> bool {
+    let mut report = |prefix: &mut Vec<u32>, values: &[u32]| {
+        prefix.extend_from_slice(values);
+        let stop = found(prefix);
+        prefix.truncate(prefix.len() - values.len());
+        stop
+    };
+    match k {
+        0 => target == 0 && report(prefix, &[]),
+        1 => sorted.binary_search_by_key(&target, |&n| n as u64)
+            .is_ok_and(|i| report(prefix, &[sorted[i]])),
+        2 => {
+            let (mut i, mut j) = (0, sorted.len().saturating_sub(1));
+            while i < j {
+                let (low, high) = (sorted[i], sorted[j]);
+                match (low as u64 + high as u64).cmp(&target) {
+                    std::cmp::Ordering::Less => i += 1,
+                    std::cmp::Ordering::Greater => j -= 1,
+                    std::cmp::Ordering::Equal => {
+                        if report(prefix, &[low, high]) {
+                            return true;
+                        }
+                        while i < j && sorted[i] == low {
+                            i += 1;
+                        }
+                        while i < j && sorted[j] == high {
+                            j -= 1;
+                        }
+                    }
+                }
+            }
+            false
+        }
+        _ => {
+            let largest = sorted.last().map_or(0, |&n| n as u64);
+            for (i, &n) in sorted.iter().enumerate() {
+                if i > 0 && sorted[i - 1] == n {
+                    continue;
+                }
+                let n = n as u64;
+                if n.saturating_mul(k as u64) > target {
+                    break;
+                }
+                if n.saturating_add(largest.saturating_mul(k as u64 - 1)) < target {
+                    continue;
+                }
+                prefix.push(n as u32);
+                let stop = k_sum(&sorted[i + 1..], k - 1, target - n, prefix, found);
+                prefix.pop();
+                if stop {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    type Input = Vec<u32>;
+    type Output = Answer<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, number).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(numbers: &Vec<u32>) -> Answer<u32> {
+        a::solve(numbers)
+    }
+
+    fn part2(numbers: &Vec<u32>) -> Answer<u32> {
+        b::solve(numbers)
+    }
+}
+
+const USAGE: &str = "usage: aoc01<a|b> [--target <n>] [--k <n>] [--all | --each | --count] [input]
+
+Prints the product of the first k entries that add up to the target, by default 2020, where k is 2
+for part a and 3 for part b. With --all, prints every distinct tuple of values that does; with
+--each, every combination of lines that does, so repeated values are told apart; with --count,
+how many such combinations there are.";
+
+fn usage(message: &str) -> ! {
+    eprintln!("aoc01: {}\n{}", message, USAGE);
+    exit(2)
+}
+
+/// The body of the day 1 binaries, which take the target and number of entries as options.
+pub fn main(part: Part) {
+    let mut args = std::env::args().skip(1);
+    let mut target = TARGET;
+    let mut k = match part {
+        Part::A => 2,
+        Part::B => 3,
+    };
+    let mut all = false;
+    let mut each = false;
+    let mut count = false;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(|| usage(&format!("{} needs a number", name)));
+        match arg.as_str() {
+            "--target" => target = value("--target"),
+            "--k" => k = value("--k") as usize,
+            "--all" => all = true,
+            "--each" => each = true,
+            "--count" => count = true,
+            _ if path.is_none() => path = Some(arg),
+            _ => usage(&format!("unexpected argument {:?}", arg)),
+        }
+    }
+
+    let input = input::from_arg(Day01::DAY, path.as_deref());
+    let numbers = Day01::parse(&input.text).unwrap_or_else(|e| {
+        eprintln!("{}", e.diagnostic(&input.source));
+        exit(1)
+    });
+    if count {
+        println!("{}", count_k_sums(&numbers, k, target));
+        return;
+    }
+    if each {
+        let mut found = false;
+        for positions in k_sums(&numbers, k, target) {
+            found = true;
+            let lines = positions.iter().map(|i| (i + 1).to_string()).collect::<Vec<_>>();
+            let terms = positions.iter().map(|&i| numbers[i].to_string()).collect::<Vec<_>>();
+            println!("lines {}: {} = {}", lines.join(", "), terms.join(" + "), target);
+        }
+        if !found {
+            eprintln!("aoc01: no {} entries add up to {}", k, target);
+            exit(1);
+        }
+        return;
+    }
+    let tuples = if all {
+        find_all_k_sums(&numbers, k, target)
+    } else {
+        find_k_sum(&numbers, k, target).into_iter().collect()
+    };
+    if tuples.is_empty() {
+        eprintln!("aoc01: no {} entries add up to {}", k, target);
+        exit(1);
+    }
+    for tuple in tuples {
+        let product = tuple.iter().map(|&n| n as u128).product::<u128>();
+        if all {
+            let terms = tuple.iter().map(u32::to_string).collect::<Vec<_>>();
+            println!("{} = {}, product {}", terms.join(" + "), target, product);
+        } else {
+            println!("{}", product);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: [u32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn test_find_k_sum() {
+        assert_eq!(Some(vec![299, 1721]), find_k_sum(&EXAMPLE, 2, TARGET));
+        assert_eq!(Some(vec![366, 675, 979]), find_k_sum(&EXAMPLE, 3, TARGET));
+        assert_eq!(None, find_k_sum(&EXAMPLE, 4, TARGET));
+        assert_eq!(Some(vec![299, 366, 675]), find_k_sum(&EXAMPLE, 3, 1340));
+        assert_eq!(Some(vec![1456]), find_k_sum(&EXAMPLE, 1, 1456));
+        assert_eq!(Some(vec![]), find_k_sum(&EXAMPLE, 0, 0));
+        assert_eq!(None, find_k_sum(&EXAMPLE, 0, TARGET));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        assert_eq!(Answer::Found(514579), Day01::part1(&EXAMPLE.to_vec()));
+        assert_eq!(Answer::None("no two entries add up to 2020"), Day01::part1(&vec![2019, 2]));
+        assert_eq!(Answer::None("no three entries add up to 2020"), Day01::part2(&vec![1010, 1010]));
+    }
+
+    #[test]
+    fn test_duplicates() {
+        assert_eq!(Some(vec![1010, 1010]), find_k_sum(&[1010, 1010], 2, TARGET));
+        assert_eq!(None, find_k_sum(&[1010], 2, TARGET));
+        assert_eq!(vec![vec![1, 3], vec![2, 2]], find_all_k_sums(&[3, 1, 2, 1, 2, 3], 2, 4));
+        assert_eq!(vec![vec![1, 1, 2]], find_all_k_sums(&[1, 1, 1, 2, 2], 3, 4));
+    }
+
+    #[test]
+    fn test_numbers_larger_than_target() {
+        assert_eq!(Some(vec![0, 2020]), find_k_sum(&[5000, 2020, u32::MAX, 0], 2, TARGET));
+        assert_eq!(None, find_k_sum(&[u32::MAX, u32::MAX, 1], 2, u32::MAX));
+        assert_eq!(Some(vec![1, u32::MAX - 1]), find_k_sum(&[u32::MAX, u32::MAX - 1, 1], 2, u32::MAX));
+    }
+
+    #[test]
+    fn test_k_sums() {
+        assert_eq!(vec![vec![0, 3]], k_sums(&EXAMPLE, 2, TARGET).collect::<Vec<_>>());
+        assert_eq!(vec![vec![1, 2, 4]], k_sums(&EXAMPLE, 3, TARGET).collect::<Vec<_>>());
+        assert_eq!(vec![Vec::<usize>::new()], k_sums(&EXAMPLE, 0, 0).collect::<Vec<_>>());
+        assert_eq!(0, k_sums(&[], 2, TARGET).count());
+    }
+
+    #[test]
+    fn test_k_sums_with_repeated_values() {
+        assert_eq!(vec![vec![0, 1]], k_sums(&[1010, 1010], 2, TARGET).collect::<Vec<_>>());
+        assert_eq!(0, k_sums(&[1010], 2, TARGET).count());
+        let mut triple = k_sums(&[1010, 1010, 1010], 2, TARGET).collect::<Vec<_>>();
+        triple.sort();
+        assert_eq!(vec![vec![0, 1], vec![0, 2], vec![1, 2]], triple);
+        assert_eq!(2, k_sums(&[1010, 0, 1010, 0], 3, TARGET).count());
+    }
+
+    #[test]
+    fn test_count_k_sums() {
+        assert_eq!(1, count_k_sums(&EXAMPLE, 2, TARGET));
+        assert_eq!(1, count_k_sums(&[1010, 1010], 2, TARGET));
+        assert_eq!(3, count_k_sums(&[1010, 1010, 1010], 2, TARGET));
+        assert_eq!(0, count_k_sums(&[1010], 2, TARGET));
+        assert_eq!(2, count_k_sums(&[1010, 0, 1010, 0], 3, TARGET));
+        assert_eq!(4950, count_k_sums(&[1010; 100], 2, TARGET));
+        assert_eq!(1, count_k_sums(&[], 0, 0));
+    }
+
+    /// The positions of every combination of `k` of `numbers` adding up to `target`, the slow way.
+    fn brute_force_positions(numbers: &[u32], k: usize, target: u32) -> Vec<Vec<usize>> {
+        (0..1usize << numbers.len())
+            .filter(|mask| mask.count_ones() as usize == k)
+            .map(|mask| (0..numbers.len()).filter(|i| mask & 1 << i != 0).collect::<Vec<_>>())
+            .filter(|positions| positions.iter().map(|&i| numbers[i] as u64).sum::<u64>() == target as u64)
+            .collect()
+    }
+
+    /// Every distinct ascending tuple of `k` of `numbers` adding up to `target`, the slow way.
+    fn brute_force(numbers: &[u32], k: usize, target: u32) -> Vec<Vec<u32>> {
+        let mut tuples = (0..1usize << numbers.len())
+            .filter(|mask| mask.count_ones() as usize == k)
+            .map(|mask| {
+                let mut tuple = (0..numbers.len()).filter(|i| mask & 1 << i != 0).map(|i| numbers[i]).collect::<Vec<_>>();
+                tuple.sort_unstable();
+                tuple
+            })
+            .filter(|tuple| tuple.iter().map(|&n| n as u64).sum::<u64>() == target as u64)
+            .collect::<Vec<_>>();
+        tuples.sort();
+        tuples.dedup();
+        tuples
+    }
+
+    proptest! {
+        #[test]
+        fn find_all_k_sums_matches_brute_force(numbers in vec(0..40u32, 0..12), k in 0..5usize, target in 0..100u32) {
+            let expected = brute_force(&numbers, k, target);
+            prop_assert_eq!(&expected, &find_all_k_sums(&numbers, k, target));
+            prop_assert_eq!(expected.first().cloned(), find_k_sum(&numbers, k, target));
+        }
+
+        #[test]
+        fn k_sums_match_brute_force(numbers in vec(0..20u32, 0..12), k in 0..5usize, target in 0..60u32) {
+            let mut expected = brute_force_positions(&numbers, k, target);
+            expected.sort();
+            let mut found = k_sums(&numbers, k, target).collect::<Vec<_>>();
+            found.sort();
+            prop_assert_eq!(&expected, &found);
+            prop_assert_eq!(expected.len() as u128, count_k_sums(&numbers, k, target));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+use super::policy::PasswordPolicy;
+use super::Entry;
+
+/// The sled rental policy: `char` appears a number of times in `times`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Count {
+    pub times: RangeInclusive<usize>,
+    pub char: char,
+}
+
+impl PasswordPolicy for Count {
+    fn allows(&self, password: &str) -> bool {
+        let count = password.chars().filter(|c| *c == self.char).count();
+        self.times.contains(&count)
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "count {}-{} {}", self.times.start(), self.times.end(), self.char)
+    }
+}
+
+impl Entry {
+    pub fn count_policy(&self) -> Count {
+        Count { times: self.first..=self.second, char: self.char }
+    }
+}
+
+pub fn valid_passwords(entries: &[Entry]) -> usize {
+    entries.iter()
+        .filter(|e| e.count_policy().allows(&e.password))
+        .count()
+}
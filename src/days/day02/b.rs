@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+
+use super::policy::PasswordPolicy;
+use super::Entry;
+
+/// The Official Toboggan Corporate Policy: exactly one of the characters at positions `first`
+/// and `second`, counting from one, is `char`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Positions {
+    pub first: usize,
+    pub second: usize,
+    pub char: char,
+}
+
+impl PasswordPolicy for Positions {
+    fn allows(&self, password: &str) -> bool {
+        let (first, second) = (self.first.wrapping_sub(1), self.second.wrapping_sub(1));
+        password
+            .chars()
+            .enumerate()
+            .filter(|(i, c)| (*i == first || *i == second) && *c == self.char)
+            .count()
+            == 1
+    }
+}
+
+impl Display for Positions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "at {}-{} {}", self.first, self.second, self.char)
+    }
+}
+
+impl Entry {
+    pub fn positions_policy(&self) -> Positions {
+        Positions { first: self.first, second: self.second, char: self.char }
+    }
+}
+
+pub fn valid_passwords(entries: &[Entry]) -> usize {
+    entries.iter()
+        .filter(|e| e.positions_policy().allows(&e.password))
+        .count()
+}
@@ -0,0 +1,144 @@
+use std::process::exit;
+
+use crate::days::Part;
+use crate::input::{self, Source};
+use crate::solution::{self, parse_lines, ParseError, Solution, Unexpected};
+
+use self::policy::{parse_checked, report};
+
+pub mod a;
+pub mod b;
+pub mod policy;
+pub mod stream;
+
+/// A password and the policy it was created under: `first-second char: password`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub first: usize,
+    pub second: usize,
+    pub char: char,
+    pub password: String,
+}
+
+/// Parses `line` with the same grammar as [`stream::parse_entry`], so that input is read the same
+/// with or without `--stream`.
+fn parse_entry(line: &str) -> Result<Entry, Unexpected<'_>> {
+    let entry = stream::parse_entry(line.as_bytes())
+        .map_err(|(offset, expected)| Unexpected::new(line[offset..].trim_end(), expected))?;
+    Ok(Entry {
+        first: entry.first,
+        second: entry.second,
+        char: char::from(entry.char),
+        password: String::from_utf8_lossy(entry.password).into_owned(),
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    type Input = Vec<Entry>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_entry).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(entries: &Vec<Entry>) -> usize {
+        a::valid_passwords(entries)
+    }
+
+    fn part2(entries: &Vec<Entry>) -> usize {
+        b::valid_passwords(entries)
+    }
+}
+
+const USAGE: &str = "usage: aoc02<a|b> [--report | --stream] [input]
+
+Prints how many passwords follow the policy written before them. With --report, policies are
+read in the rule language of the policy module, which adds rules like `min 8` or `has digit` to
+the puzzle's, and each password that breaks its policy is listed with the rules it breaks. With
+--stream, the input is counted as it is read, in constant memory, for inputs too large to load.";
+
+fn usage(message: &str) -> ! {
+    eprintln!("aoc02: {}\n{}", message, USAGE);
+    exit(2)
+}
+
+/// Counts the valid passwords of `source` without reading it all into memory.
+fn stream(source: &Source) -> Result<stream::Tally, stream::StreamError> {
+    match source {
+        #[cfg(feature = "memmap2")]
+        Source::Path(path) => stream::tally_file(path),
+        #[cfg(not(feature = "memmap2"))]
+        Source::Path(path) => stream::tally_reader(std::fs::File::open(path)?, stream::CHUNK),
+        Source::Stdin => stream::tally_reader(std::io::stdin().lock(), stream::CHUNK),
+    }
+}
+
+/// The body of the day 2 binaries, which can also report on passwords with richer policies.
+pub fn main(part: Part) {
+    let mut report_broken = false;
+    let mut streaming = false;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--report" => report_broken = true,
+            "--stream" => streaming = true,
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => usage(&format!("unexpected argument {:?}", arg)),
+        }
+    }
+    if report_broken && streaming {
+        usage("--report and --stream can not be combined");
+    }
+    if streaming {
+        let source = path.as_deref().map_or_else(|| Source::default_for(Day02::DAY), Source::from_arg);
+        match stream(&source) {
+            Ok(tally) => println!("{}", tally.valid(part)),
+            Err(e) => {
+                eprintln!("error: {}: {}", source, e);
+                exit(1)
+            }
+        }
+        return;
+    }
+    if !report_broken {
+        return solution::main::<Day02>(part);
+    }
+
+    let input = input::from_arg(Day02::DAY, path.as_deref());
+    let checked = parse_lines(&input.text, |line| parse_checked(line, part)).unwrap_or_else(|e| {
+        eprintln!("{}", ParseError::new(Day02::DAY, &input.text, e).diagnostic(&input.source));
+        exit(1)
+    });
+    let violations = report(&checked);
+    for violation in &violations {
+        println!("{}", violation);
+    }
+    println!("{} of {} passwords are valid", checked.len() - violations.len(), checked.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day02::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 3: expected '-', found \" b: cdefg\"", error("1-3 a: abcde\n13 b: cdefg\n"));
+        assert_eq!("line 1, column 3: expected a number, found \"x a: abcde\"", error("1-x a: abcde\n"));
+        assert_eq!("line 1, column 6: expected ':', found \"b: abcde\"", error("1-3 ab: abcde\n"));
+        assert_eq!("line 1, column 7: expected ' ', found nothing", error("1-3 a:\n"));
+    }
+
+    #[test]
+    fn test_stream_parses_alike() {
+        let input = "1-3 a: abcde\n1-3 a: abc junk\n";
+        assert_eq!("line 2, column 11: expected the end of the line, found \" junk\"", Day02::parse(input).unwrap_err().to_string());
+        assert_eq!("line 2, column 11: expected the end of the line",
+                   stream::tally_reader(input.as_bytes(), stream::CHUNK).unwrap_err().to_string());
+    }
+}
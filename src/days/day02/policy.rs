@@ -0,0 +1,331 @@
+//! Password policies beyond the two of the puzzle, and a small language for writing them.
+//!
+//! A policy is written before the password as rules separated by `, `:
+//!
+//! - `count 1-3 a`: `a` appears one to three times (part a's policy)
+//! - `at 1-3 a`: exactly one of the first and third characters is `a` (part b's policy)
+//! - `1-3 a`: whichever of the two the part being solved uses
+//! - `min 8`: at least eight characters
+//! - `has upper`: at least one character of a class, `lower`, `upper`, `digit` or `symbol`
+//! - `not abc`: does not contain `abc`
+//! - `like ^[a-z]+$`: matches a regular expression, which cannot contain `, `
+//!
+//! so that `min 8, has digit, not password: hunter2` is a line of input.
+
+use std::fmt::{Debug, Display, Formatter};
+
+use regex::Regex;
+
+use crate::days::Part;
+use crate::solution::{number, Unexpected};
+
+use super::a::Count;
+use super::b::Positions;
+
+pub trait PasswordPolicy: Debug + Display + Send + Sync {
+    fn allows(&self, password: &str) -> bool;
+
+    /// The rules `password` breaks, written as they are in a policy.
+    fn broken(&self, password: &str) -> Vec<String> {
+        if self.allows(password) {
+            Vec::new()
+        } else {
+            vec![self.to_string()]
+        }
+    }
+}
+
+/// At least this many characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinLength(pub usize);
+
+impl PasswordPolicy for MinLength {
+    fn allows(&self, password: &str) -> bool {
+        password.chars().count() >= self.0
+    }
+}
+
+impl Display for MinLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "min {}", self.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+}
+
+impl CharClass {
+    const ALL: [(CharClass, &'static str); 4] = [
+        (CharClass::Lower, "lower"),
+        (CharClass::Upper, "upper"),
+        (CharClass::Digit, "digit"),
+        (CharClass::Symbol, "symbol"),
+    ];
+
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            CharClass::Lower => c.is_lowercase(),
+            CharClass::Upper => c.is_uppercase(),
+            CharClass::Digit => c.is_ascii_digit(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        CharClass::ALL.iter().find(|(class, _)| *class == self).unwrap().1
+    }
+}
+
+/// At least one character of a class.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Requires(pub CharClass);
+
+impl PasswordPolicy for Requires {
+    fn allows(&self, password: &str) -> bool {
+        password.chars().any(|c| self.0.contains(c))
+    }
+}
+
+impl Display for Requires {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "has {}", self.0.name())
+    }
+}
+
+/// Does not contain a substring.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Forbids(pub String);
+
+impl PasswordPolicy for Forbids {
+    fn allows(&self, password: &str) -> bool {
+        !password.contains(&self.0)
+    }
+}
+
+impl Display for Forbids {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not {}", self.0)
+    }
+}
+
+/// Matches a regular expression somewhere, unless it is anchored.
+#[derive(Clone, Debug)]
+pub struct Matches(pub Regex);
+
+impl PasswordPolicy for Matches {
+    fn allows(&self, password: &str) -> bool {
+        self.0.is_match(password)
+    }
+}
+
+impl Display for Matches {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "like {}", self.0.as_str())
+    }
+}
+
+/// Every one of several policies.
+#[derive(Debug, Default)]
+pub struct All(pub Vec<Box<dyn PasswordPolicy>>);
+
+impl PasswordPolicy for All {
+    fn allows(&self, password: &str) -> bool {
+        self.0.iter().all(|policy| policy.allows(password))
+    }
+
+    fn broken(&self, password: &str) -> Vec<String> {
+        self.0.iter().flat_map(|policy| policy.broken(password)).collect()
+    }
+}
+
+impl Display for All {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, policy) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", policy)?;
+        }
+        Ok(())
+    }
+}
+
+/// `first-second char`, as in the puzzle input.
+fn range_and_char(s: &str) -> Result<(usize, usize, char), Unexpected<'_>> {
+    let (range, char) = s.split_once(' ').ok_or_else(|| Unexpected::end_of(s, "a letter"))?;
+    let (first, second) = range.split_once('-').ok_or_else(|| Unexpected::new(range, "a range like 1-3"))?;
+    let char = match char.chars().collect::<Vec<_>>().as_slice() {
+        &[c] => c,
+        _ => return Err(Unexpected::new(char, "a letter")),
+    };
+    Ok((number(first)?, number(second)?, char))
+}
+
+fn count(s: &str) -> Result<Box<dyn PasswordPolicy>, Unexpected<'_>> {
+    let (first, second, char) = range_and_char(s)?;
+    Ok(Box::new(Count { times: first..=second, char }))
+}
+
+fn positions(s: &str) -> Result<Box<dyn PasswordPolicy>, Unexpected<'_>> {
+    let (first, second, char) = range_and_char(s)?;
+    Ok(Box::new(Positions { first, second, char }))
+}
+
+/// One rule of a policy, where `1-3 a` means the policy of `part`.
+pub fn parse_rule(rule: &str, part: Part) -> Result<Box<dyn PasswordPolicy>, Unexpected<'_>> {
+    let (word, rest) = rule.split_once(' ').unwrap_or((rule, &rule[rule.len()..]));
+    match word {
+        "count" => count(rest),
+        "at" => positions(rest),
+        "min" => Ok(Box::new(MinLength(number(rest)?))),
+        "has" => CharClass::ALL.iter()
+            .find(|(_, name)| *name == rest)
+            .map(|&(class, _)| Box::new(Requires(class)) as Box<dyn PasswordPolicy>)
+            .ok_or_else(|| Unexpected::new(rest, "lower, upper, digit or symbol")),
+        "not" if !rest.is_empty() => Ok(Box::new(Forbids(rest.to_string()))),
+        "not" => Err(Unexpected::end_of(rule, "a substring")),
+        "like" => Regex::new(rest)
+            .map(|regex| Box::new(Matches(regex)) as Box<dyn PasswordPolicy>)
+            .map_err(|_| Unexpected::new(rest, "a regular expression")),
+        _ if word.contains('-') => match part {
+            Part::A => count(rule),
+            Part::B => positions(rule),
+        },
+        _ => Err(Unexpected::new(word, "a rule like `1-3 a`, `min 8`, `has digit`, `not abc` or `like ^a`")),
+    }
+}
+
+/// Rules separated by `, `, all of which a password must follow.
+pub fn parse_policy(rules: &str, part: Part) -> Result<Box<dyn PasswordPolicy>, Unexpected<'_>> {
+    let mut policies = rules.split(", ")
+        .map(|rule| parse_rule(rule, part))
+        .collect::<Result<Vec<_>, _>>()?;
+    if policies.len() == 1 {
+        Ok(policies.pop().unwrap())
+    } else {
+        Ok(Box::new(All(policies)))
+    }
+}
+
+/// A password and the policy written before it.
+#[derive(Debug)]
+pub struct Checked {
+    pub policy: Box<dyn PasswordPolicy>,
+    pub password: String,
+}
+
+/// A line of input with a policy written in the rule language: `rules: password`.
+pub fn parse_checked(line: &str, part: Part) -> Result<Checked, Unexpected<'_>> {
+    let (rules, password) = line.rsplit_once(": ")
+        .ok_or_else(|| Unexpected::end_of(line.trim_end_matches(':'), "': ' and a password"))?;
+    let password = password.trim();
+    if password.is_empty() {
+        return Err(Unexpected::end_of(line, "a password"));
+    }
+    Ok(Checked { policy: parse_policy(rules, part)?, password: password.to_string() })
+}
+
+/// A password that does not follow its policy, and the rules it breaks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Violation<'a> {
+    /// The line of the password, counting from one.
+    pub line: usize,
+    pub password: &'a str,
+    pub broken: Vec<String>,
+}
+
+impl Display for Violation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {} breaks {}", self.line, self.password, self.broken.join(", "))
+    }
+}
+
+/// The passwords that do not follow their policies.
+pub fn report(checked: &[Checked]) -> Vec<Violation<'_>> {
+    checked.iter()
+        .enumerate()
+        .filter_map(|(i, c)| {
+            let broken = c.policy.broken(&c.password);
+            if broken.is_empty() {
+                None
+            } else {
+                Some(Violation { line: i + 1, password: &c.password, broken })
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{parse_lines, ParseError};
+
+    use super::*;
+
+    fn policy(rules: &str, part: Part) -> Box<dyn PasswordPolicy> {
+        parse_policy(rules, part).unwrap()
+    }
+
+    #[test]
+    fn test_puzzle_policies() {
+        assert!(policy("1-3 a", Part::A).allows("abcde"));
+        assert!(!policy("1-3 b", Part::A).allows("cdefg"));
+        assert!(policy("2-9 c", Part::A).allows("ccccccccc"));
+        assert!(policy("1-3 a", Part::B).allows("abcde"));
+        assert!(!policy("2-9 c", Part::B).allows("ccccccccc"));
+        assert!(!policy("count 2-9 c", Part::B).allows("c"));
+        assert!(policy("at 1-3 a", Part::A).allows("cba"));
+    }
+
+    #[test]
+    fn test_composed_policies() {
+        let strong = policy("min 8, has upper, has digit, has symbol, not password, like ^[^0-9]", Part::A);
+        assert!(strong.allows("Tr0ub4dor&3"));
+        assert!(strong.broken("Tr0ub4dor&3").is_empty());
+        assert_eq!(vec!["min 8", "has upper", "has symbol"], strong.broken("hunt3r"));
+        assert_eq!(vec!["has upper", "not password", "like ^[^0-9]"], strong.broken("1password!"));
+        assert!(policy("has lower", Part::A).allows("Ab"));
+        assert!(!policy("has lower", Part::A).allows("AB"));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for rules in ["count 1-3 a", "at 2-9 c", "min 8, has digit, not abc, like ^[a-z]+$"] {
+            assert_eq!(rules, policy(rules, Part::A).to_string());
+        }
+        assert_eq!("count 1-3 a", policy("1-3 a", Part::A).to_string());
+        assert_eq!("at 1-3 a", policy("1-3 a", Part::B).to_string());
+    }
+
+    #[test]
+    fn test_report() {
+        let input = "1-3 a: abcde\n1-3 b, min 6: cdefg\nmin 4, not cc: ccccccccc\n";
+        let checked = parse_lines(input, |line| parse_checked(line, Part::A)).unwrap();
+        let violations = report(&checked);
+        assert_eq!(vec![
+            "line 2: cdefg breaks count 1-3 b, min 6",
+            "line 3: ccccccccc breaks not cc",
+        ], violations.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+        assert_eq!(Violation { line: 3, password: "ccccccccc", broken: vec!["not cc".to_string()] }, violations[1]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| {
+            let e = parse_lines(input, |line| parse_checked(line, Part::A)).unwrap_err();
+            ParseError::new(2, input, e).to_string()
+        };
+        assert_eq!("line 1, column 1: expected a rule like `1-3 a`, `min 8`, `has digit`, `not abc` or `like ^a`, \
+                    found \"max\"", error("max 3: abc"));
+        assert_eq!("line 1, column 12: expected lower, upper, digit or symbol, found \"vowel\"", error("min 3, has vowel: abc"));
+        assert_eq!("line 1, column 6: expected a regular expression, found \"[a-\"", error("like [a-: abc"));
+        assert_eq!("line 1, column 4: expected a substring, found nothing", error("not: abc"));
+        assert_eq!("line 1, column 6: expected ': ' and a password, found nothing", error("1-3 a"));
+        assert_eq!("line 1, column 5: expected a letter, found \"ab\"", error("1-3 ab: abc"));
+        assert_eq!("line 1, column 5: expected a number, found \"x\"", error("min x: abc"));
+    }
+}
@@ -0,0 +1,327 @@
+//! Counting valid passwords in input too large to hold as `Entry`s, or as a `String` at all.
+//!
+//! Lines are parsed as bytes into entries that borrow their password, and both parts are counted
+//! in the same pass, so a file is read once in fixed size chunks or memory mapped without
+//! allocating per line. Positions count bytes, which is characters for the ASCII passwords of
+//! the puzzle.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{ErrorKind, Read};
+
+use crate::days::Part;
+
+/// The largest chunk read at a time, and so the longest line that can be read.
+pub const CHUNK: usize = 64 * 1024;
+
+/// An entry borrowed from a line of input: `first-second char: password`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EntryRef<'a> {
+    pub first: usize,
+    pub second: usize,
+    pub char: u8,
+    pub password: &'a [u8],
+}
+
+impl EntryRef<'_> {
+    /// Whether the password follows the sled rental policy of part a.
+    pub fn count_valid(&self) -> bool {
+        let count = self.password.iter().filter(|&&b| b == self.char).count();
+        (self.first..=self.second).contains(&count)
+    }
+
+    /// Whether the password follows the corporate policy of part b.
+    pub fn positions_valid(&self) -> bool {
+        let at = |position: usize| position.checked_sub(1).and_then(|i| self.password.get(i)) == Some(&self.char);
+        if self.first == self.second {
+            at(self.first)
+        } else {
+            at(self.first) != at(self.second)
+        }
+    }
+}
+
+/// A line that is not an entry, and where.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Malformed {
+    /// The line the problem is on, counting from one.
+    pub line: usize,
+    /// The byte the problem starts at, counting from one.
+    pub column: usize,
+    pub expected: &'static str,
+}
+
+impl Display for Malformed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)
+    }
+}
+
+impl Error for Malformed {}
+
+/// Parses `line` left to right, or returns the offset of the problem and what was expected.
+pub fn parse_entry(line: &[u8]) -> Result<EntryRef<'_>, (usize, &'static str)> {
+    let mut at = 0;
+    let number = |at: &mut usize| {
+        let start = *at;
+        let mut n = 0usize;
+        while let Some(digit) = line.get(*at).filter(|b| b.is_ascii_digit()) {
+            n = n.checked_mul(10)
+                .and_then(|n| n.checked_add((digit - b'0') as usize))
+                .ok_or((start, "a smaller number"))?;
+            *at += 1;
+        }
+        if *at == start { Err((start, "a number")) } else { Ok(n) }
+    };
+    let expect = |at: &mut usize, byte: u8, expected: &'static str| {
+        if line.get(*at) == Some(&byte) {
+            *at += 1;
+            Ok(())
+        } else {
+            Err((*at, expected))
+        }
+    };
+
+    let first = number(&mut at)?;
+    expect(&mut at, b'-', "'-'")?;
+    let second = number(&mut at)?;
+    expect(&mut at, b' ', "' '")?;
+    let char = *line.get(at).filter(|b| b.is_ascii_graphic() && **b != b':').ok_or((at, "a letter"))?;
+    at += 1;
+    expect(&mut at, b':', "':'")?;
+    expect(&mut at, b' ', "' '")?;
+    let password = line[at..].trim_ascii_end();
+    if password.is_empty() {
+        return Err((at, "a password"));
+    }
+    if let Some(space) = password.iter().position(u8::is_ascii_whitespace) {
+        return Err((at + space, "the end of the line"));
+    }
+    Ok(EntryRef { first, second, char, password })
+}
+
+/// How many entries there are, and how many follow the policy of each part.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub entries: usize,
+    pub count_valid: usize,
+    pub positions_valid: usize,
+}
+
+impl Tally {
+    pub fn add(&mut self, entry: &EntryRef<'_>) {
+        self.entries += 1;
+        self.count_valid += entry.count_valid() as usize;
+        self.positions_valid += entry.positions_valid() as usize;
+    }
+
+    /// The answer to `part`.
+    pub fn valid(&self, part: Part) -> usize {
+        match part {
+            Part::A => self.count_valid,
+            Part::B => self.positions_valid,
+        }
+    }
+
+    /// Adds the entries of whole lines of `input`, the first of them line `first_line`, and
+    /// returns how many lines there were. A newline at the end does not start another line.
+    fn add_lines(&mut self, input: &[u8], first_line: usize) -> Result<usize, Malformed> {
+        if input.is_empty() {
+            return Ok(0);
+        }
+        let mut lines = 0;
+        for line in input.strip_suffix(b"\n").unwrap_or(input).split(|&b| b == b'\n') {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            let entry = parse_entry(line).map_err(|(offset, expected)| {
+                Malformed { line: first_line + lines, column: offset + 1, expected }
+            })?;
+            self.add(&entry);
+            lines += 1;
+        }
+        Ok(lines)
+    }
+}
+
+/// Counts the valid passwords of input already in memory.
+pub fn tally(input: &[u8]) -> Result<Tally, Malformed> {
+    let mut tally = Tally::default();
+    tally.add_lines(input, 1)?;
+    Ok(tally)
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    Malformed(Malformed),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{}", e),
+            StreamError::Malformed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Malformed(e) => Some(e),
+        }
+    }
+}
+
+impl From<std::io::Error> for StreamError {
+    fn from(e: std::io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<Malformed> for StreamError {
+    fn from(e: Malformed) -> Self {
+        StreamError::Malformed(e)
+    }
+}
+
+/// Counts the valid passwords read from `reader`, holding no more than `chunk` bytes of it at a
+/// time. No line may be longer than that.
+pub fn tally_reader<R: Read>(mut reader: R, chunk: usize) -> Result<Tally, StreamError> {
+    let mut buffer = vec![0; chunk.max(1)];
+    let mut filled = 0;
+    let mut tally = Tally::default();
+    let mut line = 1;
+    loop {
+        let read = match reader.read(&mut buffer[filled..]) {
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        if read == 0 {
+            tally.add_lines(&buffer[..filled], line)?;
+            return Ok(tally);
+        }
+        filled += read;
+        match buffer[..filled].iter().rposition(|&b| b == b'\n') {
+            Some(end) => {
+                line += tally.add_lines(&buffer[..=end], line)?;
+                buffer.copy_within(end + 1..filled, 0);
+                filled -= end + 1;
+            }
+            None if filled == buffer.len() => {
+                return Err(Malformed { line, column: filled + 1, expected: "the end of the line" }.into());
+            }
+            None => {}
+        }
+    }
+}
+
+/// Counts the valid passwords of a file by mapping it into memory.
+#[cfg(feature = "memmap2")]
+pub fn tally_file(path: &std::path::Path) -> Result<Tally, StreamError> {
+    let file = std::fs::File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(Tally::default());
+    }
+    // Safety: the map is only read while it lives, and the input is not expected to change while
+    // it is counted; if it is truncated meanwhile, reading it may fault.
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    Ok(tally(&map)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::days::day02::Day02;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    const EXAMPLE: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    #[test]
+    fn test_parse_entry() {
+        assert_eq!(Ok(EntryRef { first: 1, second: 3, char: b'a', password: b"abcde" }), parse_entry(b"1-3 a: abcde"));
+        assert_eq!(Ok(EntryRef { first: 12, second: 16, char: b'l', password: b"kl" }), parse_entry(b"12-16 l: kl \t"));
+        assert_eq!(Err((0, "a number")), parse_entry(b""));
+        assert_eq!(Err((2, "'-'")), parse_entry(b"13 b: cdefg"));
+        assert_eq!(Err((2, "a number")), parse_entry(b"1-x a: abcde"));
+        assert_eq!(Err((5, "':'")), parse_entry(b"1-3 ab: abcde"));
+        assert_eq!(Err((7, "a password")), parse_entry(b"1-3 a: "));
+        assert_eq!(Err((9, "the end of the line")), parse_entry(b"1-3 a: ab cd"));
+        assert_eq!(Err((0, "a smaller number")), parse_entry(b"99999999999999999999-1 a: a"));
+    }
+
+    #[test]
+    fn test_tally() {
+        let expected = Tally { entries: 3, count_valid: 2, positions_valid: 1 };
+        assert_eq!(Ok(expected), tally(EXAMPLE.as_bytes()));
+        assert_eq!(Ok(expected), tally(EXAMPLE.trim_end().as_bytes()));
+        assert_eq!(Ok(expected), tally(EXAMPLE.replace('\n', "\r\n").as_bytes()));
+        assert_eq!(Ok(Tally::default()), tally(b""));
+        assert_eq!(Err(Malformed { line: 2, column: 3, expected: "'-'" }), tally(b"1-3 a: abcde\n13 b: cdefg\n"));
+        assert_eq!(Err(Malformed { line: 2, column: 1, expected: "a number" }), tally(b"1-3 a: abcde\n\n"));
+    }
+
+    #[test]
+    fn test_positions() {
+        let valid = |line: &[u8]| parse_entry(line).unwrap().positions_valid();
+        assert!(valid(b"1-3 a: abcde"));
+        assert!(!valid(b"2-9 c: ccccccccc"));
+        assert!(valid(b"3-9 a: bba"));
+        assert!(!valid(b"0-9 a: a"));
+        assert!(valid(b"2-2 a: ba"));
+    }
+
+    #[test]
+    fn test_chunks() {
+        for chunk in 17..=EXAMPLE.len() + 1 {
+            assert_eq!(tally(EXAMPLE.as_bytes()).unwrap(), tally_reader(Cursor::new(EXAMPLE), chunk).unwrap(), "chunk of {}", chunk);
+        }
+        match tally_reader(Cursor::new(EXAMPLE), 16) {
+            Err(StreamError::Malformed(e)) => assert_eq!(Malformed { line: 3, column: 17, expected: "the end of the line" }, e),
+            other => panic!("expected a line too long, got {:?}", other),
+        }
+        match tally_reader(Cursor::new("1-3 a: abcde\n1-3 b: cdefg\n2-9: c\n"), 20) {
+            Err(StreamError::Malformed(e)) => assert_eq!(Malformed { line: 3, column: 4, expected: "' '" }, e),
+            other => panic!("expected a malformed line, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_solution() {
+        let input = std::fs::read_to_string("src/bin/aoc02.txt").unwrap();
+        let entries = Day02::parse(&input).unwrap();
+        let tally = tally_reader(input.as_bytes(), 100).unwrap();
+        assert_eq!(entries.len(), tally.entries);
+        assert_eq!(Day02::part1(&entries), tally.valid(Part::A));
+        assert_eq!(Day02::part2(&entries), tally.valid(Part::B));
+    }
+
+    #[cfg(feature = "memmap2")]
+    #[test]
+    fn test_tally_file() {
+        let expected = tally(&std::fs::read("src/bin/aoc02.txt").unwrap()).unwrap();
+        assert_eq!(expected, tally_file("src/bin/aoc02.txt".as_ref()).unwrap());
+    }
+
+    #[cfg(feature = "bench")]
+    mod benches {
+        extern crate test;
+
+        use test::bench::Bencher;
+
+        use super::*;
+
+        #[bench]
+        fn bench_tally(bencher: &mut Bencher) {
+            let input = std::fs::read("src/bin/aoc02.txt").unwrap();
+            bencher.iter(|| tally(&input).unwrap());
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use super::{Map, Tile};
+
+pub fn solve(map: &Map) -> usize {
+    map.traverse(3, 1).filter(|v| *v == Tile::Tree).count()
+}
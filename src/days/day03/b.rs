@@ -0,0 +1,34 @@
+use super::{Map, Tile};
+
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub fn find_trees(map: &Map) -> usize {
+    SLOPES
+        .iter()
+        .map(|(dx, dy)| map.traverse(*dx, *dy).filter(|v| *v == Tile::Tree).count())
+        .product::<usize>()
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod benches {
+    extern crate test;
+
+    use std::io::Read;
+    use test::bench::Bencher;
+
+    use crate::days::day03::Day03;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    #[bench]
+    fn bench_traverse(bencher: &mut Bencher) {
+        let mut f = std::fs::File::open("src/bin/aoc03.txt").unwrap();
+        let mut s = String::new();
+        f.read_to_string(&mut s).unwrap();
+
+        let map = Day03::parse(&s).unwrap();
+
+        bencher.iter(move || find_trees(&map))
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::{Display, Formatter, Write};
+
+use crate::grid::{Edge, Grid};
+use crate::solution::{ParseError, Solution, Unexpected};
+
+use self::Tile::{Open, Tree};
+
+pub mod a;
+pub mod b;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+    Open,
+    Tree,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Open => '.',
+            Tree => '#',
+        })
+    }
+}
+
+/// The slope, which repeats to the right as far as it goes down.
+pub type Map = Grid<Tile>;
+
+fn parse(s: &str) -> Result<Map, Unexpected<'_>> {
+    let map = Grid::parse(s, "'.' or '#'", |c| match c {
+        '.' => Some(Open),
+        '#' => Some(Tree),
+        _ => None,
+    })?;
+    Ok(map.with_edge(Edge::Wrapping))
+}
+
+impl Grid<Tile> {
+    /// The tiles passed going `dx` right and `dy` down at a time from the top left to the bottom.
+    pub fn traverse(&self, dx: usize, dy: usize) -> impl Iterator<Item=Tile> + '_ {
+        (0..self.height())
+            .step_by(dy)
+            .enumerate()
+            .map(move |(i, y)| *self.get((i * dx) as isize, y as isize).unwrap())
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    type Input = Map;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(map: &Map) -> usize {
+        a::solve(map)
+    }
+
+    fn part2(map: &Map) -> usize {
+        b::find_trees(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day03::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 1: expected a row of 3 tiles, found \".#\"", error("..#\n.#\n"));
+        assert_eq!("line 1, column 2: expected '.' or '#', found \"x\"", error(".x#\n"));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        assert_eq!("..#\n#.#\n", Day03::parse("..#\n#.#\n").unwrap().to_string());
+    }
+}
@@ -0,0 +1,9 @@
+use super::Passport;
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+pub fn count_valid(passports: &[Passport]) -> usize {
+    passports.iter()
+        .filter(|fields| REQUIRED.iter().all(|s| fields.contains_key(*s)))
+        .count()
+}
@@ -0,0 +1,96 @@
+use std::ops::RangeInclusive;
+
+use super::Passport;
+
+fn valid_year(s: &str, valid_range: RangeInclusive<usize>) -> Option<String> {
+    if s.len() != 4 {
+        return None;
+    }
+    match s.parse::<usize>() {
+        Ok(year) if valid_range.contains(&year) => Some(s.to_string()),
+        _ => None,
+    }
+}
+
+fn valid_length(
+    s: &str,
+    valid_cm_range: RangeInclusive<usize>,
+    valid_in_range: RangeInclusive<usize>,
+) -> Option<String> {
+    if s.ends_with("cm") {
+        match s[0..s.len() - 2].parse::<usize>() {
+            Ok(l) if valid_cm_range.contains(&l) => Some(s.to_string()),
+            _ => None,
+        }
+    } else if s.ends_with("in") {
+        match s[0..s.len() - 2].parse::<usize>() {
+            Ok(l) if valid_in_range.contains(&l) => Some(s.to_string()),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+fn valid_hex_color(s: &str) -> Option<String> {
+    if s.len() != 7 {
+        return None;
+    }
+    let mut chars = s.chars();
+    if Some('#') != chars.next() {
+        return None;
+    }
+    for c in chars {
+        match c {
+            '0'..='9' | 'a'..='f' => {}
+            _ => return None,
+        }
+    }
+    Some(s.to_string())
+}
+
+fn valid_named_color(s: &str) -> Option<String> {
+    match s {
+        "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth" => Some(s.to_string()),
+        _ => None,
+    }
+}
+
+fn valid_passport_id(s: &str) -> Option<String> {
+    if s.len() != 9 {
+        return None;
+    }
+    if !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(s.to_string())
+}
+
+fn validate_passport(fields: &Passport) -> Option<()> {
+    fields
+        .get("byr")
+        .and_then(|v| valid_year(v, 1920..=2002))?;
+    fields
+        .get("iyr")
+        .and_then(|v| valid_year(v, 2010..=2020))?;
+    fields
+        .get("eyr")
+        .and_then(|v| valid_year(v, 2020..=2030))?;
+
+    fields
+        .get("hgt")
+        .and_then(|v| valid_length(v, 150..=193, 59..=76))?;
+
+    fields.get("hcl").and_then(|v| valid_hex_color(v))?;
+    fields.get("ecl").and_then(|v| valid_named_color(v))?;
+    fields.get("pid").and_then(|v| valid_passport_id(v))?;
+    Some(())
+}
+
+/// The number of valid and invalid passports.
+pub fn count_valid(passports: &[Passport]) -> (usize, usize) {
+    let valid = passports.iter()
+        .filter(|fields| validate_passport(fields).is_some())
+        .count();
+    (valid, passports.len() - valid)
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use crate::solution::{ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+pub type Passport = HashMap<String, String>;
+
+/// Splits the input into passports: `key:value` fields, with a blank line after each passport.
+pub fn parse(s: &str) -> Result<Vec<Passport>, Unexpected<'_>> {
+    let mut passports = Vec::new();
+    let mut fields = Passport::new();
+    for line in s.lines() {
+        if line.is_empty() {
+            passports.push(std::mem::take(&mut fields));
+        }
+        for item in line.split_ascii_whitespace() {
+            match item.split_once(':') {
+                Some((k, v)) if !v.is_empty() => {
+                    fields.insert(k.to_string(), v.to_string());
+                }
+                _ => return Err(Unexpected::new(item, "a key:value field")),
+            }
+        }
+    }
+    if !fields.is_empty() {
+        passports.push(fields);
+    }
+    Ok(passports)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Passport Processing";
+
+    type Input = Vec<Passport>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(passports: &Vec<Passport>) -> usize {
+        a::count_valid(passports)
+    }
+
+    fn part2(passports: &Vec<Passport>) -> usize {
+        b::count_valid(passports).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("line 3, column 10: expected a key:value field, found \"hcl\"",
+                   Day04::parse("ecl:gry\n\niyr:2013 hcl\n").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,5 @@
+use super::Seat;
+
+pub fn highest_id(seats: &[Seat]) -> usize {
+    seats.iter().map(Seat::id).max().unwrap_or(0)
+}
@@ -0,0 +1,24 @@
+use crate::solution::Answer;
+
+use super::Seat;
+
+/// The id of the only empty seat whose neighbours are both taken.
+pub fn find_seat(taken: &[Seat]) -> Answer<usize> {
+    let mut seats = [0u8; 128];
+
+    taken.iter()
+        .for_each(|seat| seats[seat.row] |= 1u8 << seat.col);
+
+    let seat = seats
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(i, _)| *i > 0 && *i < 127)
+        .filter(|(_, row)| row.count_zeros() == 1)
+        .filter(|(i, row)| seats[i - 1] & !*row == !*row && seats[i + 1] & !*row == !*row)
+        .map(|(i, row)| (i, (row.trailing_ones()) as usize))
+        .map(|(row, col)| Seat { row, col })
+        .next();
+
+    Answer::from_option(seat.map(|seat| seat.id()), "no empty seat has both neighbours taken")
+}
@@ -0,0 +1,99 @@
+use crate::solution::{parse_lines, Answer, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+#[derive(Debug, Default, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Seat {
+    row: usize,
+    col: usize,
+}
+
+impl Seat {
+    pub fn id(&self) -> usize {
+        self.row << 3 | self.col
+    }
+
+    /// A boarding pass: seven `F`/`B` halvings of the rows, then three `L`/`R` of the columns.
+    pub fn parse(s: &str) -> Result<Self, Unexpected<'_>> {
+        if s.len() != 10 {
+            return Err(Unexpected::new(s, "a boarding pass of 10 characters"));
+        }
+        s.char_indices().try_fold(Seat::default(), |seat, (i, c)| {
+            match (i, c) {
+                (0..=6, 'F') => Ok(Seat {
+                    row: seat.row << 1,
+                    ..seat
+                }),
+                (0..=6, 'B') => Ok(Seat {
+                    row: seat.row << 1 | 1,
+                    ..seat
+                }),
+                (7..=9, 'L') => Ok(Seat {
+                    col: seat.col << 1,
+                    ..seat
+                }),
+                (7..=9, 'R') => Ok(Seat {
+                    col: seat.col << 1 | 1,
+                    ..seat
+                }),
+                (0..=6, c) => Err(Unexpected::new(&s[i..i + c.len_utf8()], "'F' or 'B'")),
+                (_, c) => Err(Unexpected::new(&s[i..i + c.len_utf8()], "'L' or 'R'")),
+            }
+        })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Binary Boarding";
+
+    type Input = Vec<Seat>;
+    type Output = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Seat::parse).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(seats: &Vec<Seat>) -> Answer<usize> {
+        Answer::Found(a::highest_id(seats))
+    }
+
+    fn part2(seats: &Vec<Seat>) -> Answer<usize> {
+        b::find_seat(seats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize); 4] = [
+        ("FBFBBFFRLR", 357),
+        ("BFFFBBFRRR", 567),
+        ("FFFBBBFRRR", 119),
+        ("BBFFBBFRLL", 820),
+    ];
+
+    #[test]
+    fn test_seat() {
+        for (s, id) in &EXAMPLES {
+            assert_eq!(*id, Seat::parse(s).unwrap().id());
+        }
+    }
+
+    #[test]
+    fn test_no_empty_seat() {
+        let seats = Day05::parse("FFFFFFBLLL\nFFFFFFBLLR\nFFFFFFBLRR\nFFFFFBFLLL\n").unwrap();
+        assert_eq!(Answer::None("no empty seat has both neighbours taken"), Day05::part2(&seats));
+    }
+
+    #[test]
+    fn test_invalid_seat() {
+        let error = |input: &str| Day05::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 8: expected 'L' or 'R', found \"F\"", error("FBFBBFFRLR\nFBFBBFFFLR\n"));
+        assert_eq!("line 1, column 1: expected a boarding pass of 10 characters, found \"FBF\"", error("FBF\n"));
+    }
+}
@@ -0,0 +1,18 @@
+use super::Questionnaire;
+
+/// The sum over groups of the questions anyone in the group answered "yes" to.
+pub fn anyone(groups: &[Vec<Questionnaire>]) -> usize {
+    groups.iter()
+        .map(|group| {
+            group.iter()
+                .fold(Questionnaire(0), |q1, q2| q1.merge(*q2))
+                .count()
+        })
+        .sum::<usize>()
+}
+
+impl Questionnaire {
+    fn merge(self, other: Questionnaire) -> Questionnaire {
+        Questionnaire(self.0 | other.0)
+    }
+}
@@ -0,0 +1,12 @@
+use super::Questionnaire;
+
+/// The sum over groups of the questions everyone in the group answered "yes" to.
+pub fn everyone(groups: &[Vec<Questionnaire>]) -> usize {
+    groups.iter()
+        .map(|group| {
+            group.iter()
+                .fold(Questionnaire(u32::MAX), |a, b| Questionnaire(a.0 & b.0))
+                .count()
+        })
+        .sum()
+}
@@ -0,0 +1,68 @@
+use crate::solution::{ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// The questions, `a` to `z`, one person answered "yes" to.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Questionnaire(u32);
+
+impl Questionnaire {
+    fn count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    fn parse(s: &str) -> Result<Self, Unexpected<'_>> {
+        s.char_indices().try_fold(Questionnaire(0), |q, (i, c)| match c {
+            'a'..='z' => Ok(Questionnaire(q.0 | 1 << (c as u32 - 'a' as u32))),
+            _ => Err(Unexpected::new(&s[i..i + c.len_utf8()], "a question from 'a' to 'z'")),
+        })
+    }
+}
+
+/// The questionnaires of each group, with a blank line after each group.
+fn parse(input: &str) -> Result<Vec<Vec<Questionnaire>>, Unexpected<'_>> {
+    let mut groups = vec![Vec::new()];
+    for line in input.lines() {
+        if line.is_empty() {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push(Questionnaire::parse(line)?);
+        }
+    }
+    groups.retain(|g| !g.is_empty());
+    Ok(groups)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    type Input = Vec<Vec<Questionnaire>>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(groups: &Vec<Vec<Questionnaire>>) -> usize {
+        a::anyone(groups)
+    }
+
+    fn part2(groups: &Vec<Vec<Questionnaire>>) -> usize {
+        b::everyone(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("line 3, column 2: expected a question from 'a' to 'z', found \"B\"",
+                   Day06::parse("abc\n\naB\n").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,28 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{Color, Rule};
+
+pub fn find_containers(inner_color: &Color, rules: &[Rule]) -> usize {
+    let mut contained_by: HashMap<&Color, Vec<&Color>> = HashMap::new();
+    for rule in rules {
+        for (_, c) in &rule.1 {
+            contained_by.entry(c).or_default().push(&rule.0)
+        }
+    }
+
+    let mut queue = VecDeque::new();
+    let mut candidates: HashSet<&Color> = HashSet::new();
+    queue.push_back(inner_color);
+    candidates.insert(inner_color);
+
+    while let Some(next) = queue.pop_back() {
+        if let Some(new_candidates) = contained_by.get(next) {
+            for c in new_candidates {
+                if candidates.insert(*c) {
+                    queue.push_back(*c);
+                }
+            }
+        }
+    }
+    candidates.len() - 1
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use super::{Color, Rule};
+
+pub fn find_contained(outer_color: &Color, rules: &[Rule]) -> usize {
+    let mut contains: HashMap<&Color, Vec<(usize, &Color)>> = HashMap::new();
+    for rule in rules {
+        let v = contains.entry(&rule.0).or_default();
+        for r in &rule.1 {
+            v.push((r.0, &r.1))
+        }
+    }
+
+    fn count_contained(
+        count: usize,
+        outer: &Color,
+        contains: &HashMap<&Color, Vec<(usize, &Color)>>,
+    ) -> usize {
+        let mut acc = 0;
+        if let Some(inners) = contains.get(outer) {
+            for (inner_count, inner) in inners {
+                acc += count_contained(*inner_count, inner, contains);
+            }
+        }
+        count * (1 + acc)
+    }
+
+    count_contained(1, outer_color, &contains) - 1
+}
@@ -0,0 +1,70 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::solution::{number, parse_lines, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+#[derive(Hash, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub struct Color(String);
+
+impl Color {
+    fn new(s: impl Into<String>) -> Self {
+        Self(s.into())
+    }
+}
+
+/// A bag color and how many bags of which colors it must contain.
+#[derive(Hash, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub struct Rule(Color, Vec<(usize, Color)>);
+
+fn parse_rule(line: &str) -> Result<Rule, Unexpected<'_>> {
+    lazy_static! {
+        static ref A: Regex = Regex::new(r#"(\w+ \w+) bags contain ([^.]+)\."#).unwrap();
+        static ref B: Regex = Regex::new(r#"(\d+) (\w+ \w+)"#).unwrap();
+    }
+
+    let caps = A.captures(line)
+        .ok_or_else(|| Unexpected::new(line, "a rule like `<color> bags contain ...`"))?;
+    let bag = Color::new(&caps[1]);
+    let contains = B
+        .captures_iter(caps.get(2).unwrap().as_str())
+        .map(|cap| Ok((number(cap.get(1).unwrap().as_str())?, Color::new(&cap[2]))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Rule(bag, contains))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+
+    type Input = Vec<Rule>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_rule).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(rules: &Vec<Rule>) -> usize {
+        a::find_containers(&Color::new("shiny gold"), rules)
+    }
+
+    fn part2(rules: &Vec<Rule>) -> usize {
+        b::find_contained(&Color::new("shiny gold"), rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("line 2, column 1: expected a rule like `<color> bags contain ...`, found \"shiny gold\"",
+                   Day07::parse("faded blue bags contain no other bags.\nshiny gold\n").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,11 @@
+use crate::solution::Answer;
+
+use super::{run_program, Op};
+
+/// The accumulator just before any instruction would run a second time.
+pub fn acc_before_loop(program: &[Op]) -> Answer<i64> {
+    match run_program(program) {
+        Err((state, _)) => Answer::Found(state.acc),
+        Ok(_) => Answer::None("the program terminates without running an instruction twice"),
+    }
+}
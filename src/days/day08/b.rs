@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+
+use crate::solution::Answer;
+
+use super::{pc_offset, run_program, Op};
+
+/// The accumulator once the program terminates, after flipping the one corrupted `nop` or `jmp`.
+pub fn acc_after_fix(program: &[Op]) -> Answer<i64> {
+    let trace = match run_program(program) {
+        Err((_, trace)) => trace,
+        Ok(_) => return Answer::None("the program terminates without a fix"),
+    };
+
+    let corrupted_pc = match find_corrupted_pc(program, &trace) {
+        Some(pc) => pc,
+        None => return Answer::None("no flipped nop or jmp makes the program terminate"),
+    };
+    let program = flip_instruction(program, corrupted_pc);
+
+    let (state, _) = run_program(&program).unwrap();
+    Answer::Found(state.acc)
+}
+
+fn build_reverse_pc_tables(program: &[Op]) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let mut unmodified = vec![vec![]; program.len() + 1];
+    let mut modified = vec![vec![]; program.len() + 1];
+    for pc in 0..program.len() {
+        match program[pc] {
+            Op::Nop(n) => {
+                unmodified[pc_offset(pc, 1)].push(pc);
+                modified[pc_offset(pc, n)].push(pc)
+            }
+            Op::Acc(_) => unmodified[pc_offset(pc, 1)].push(pc),
+            Op::Jmp(n) => {
+                unmodified[pc_offset(pc, n)].push(pc);
+                modified[pc_offset(pc, 1)].push(pc)
+            }
+        }
+    }
+
+    (unmodified, modified)
+}
+
+fn find_corrupted_pc(program: &[Op], trace: &[usize]) -> Option<usize> {
+    let (unmodified, modified) = build_reverse_pc_tables(program);
+    let mut candidates: VecDeque<usize> = VecDeque::new();
+    candidates.push_front(program.len());
+
+    while let Some(pc) = candidates.pop_back() {
+        if let Some(modified_pc) = modified[pc].iter().find(|pc| trace[**pc] > 0) {
+            return Some(*modified_pc);
+        }
+        candidates.extend(&unmodified[pc]);
+    }
+    None
+}
+
+fn flip_instruction(program: &[Op], pc: usize) -> Vec<Op> {
+    let mut new_program = program.to_vec();
+    new_program[pc] = match new_program[pc] {
+        Op::Nop(n) => Op::Jmp(n),
+        Op::Jmp(n) => Op::Nop(n),
+        _ => unreachable!(),
+    };
+    new_program
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod benches {
+    extern crate test;
+
+    use std::io::Read;
+    use test::bench::Bencher;
+
+    use crate::days::day08::Day08;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    #[bench]
+    fn bench_program_fix(bencher: &mut Bencher) {
+        let mut f = std::fs::File::open("src/bin/aoc08.txt").unwrap();
+        let mut input = String::new();
+        f.read_to_string(&mut input).unwrap();
+
+
+        bencher.iter(move || {
+            let program = Day08::parse(&input).unwrap();
+            let (_, trace) = run_program(&program).unwrap_err();
+
+            let corrupted_pc = find_corrupted_pc(&program, &trace).unwrap();
+            let program = flip_instruction(&program, corrupted_pc);
+
+            let (state, _) = run_program(&program).unwrap();
+            state
+        });
+    }
+}
@@ -0,0 +1,109 @@
+use crate::solution::{number, parse_lines, Answer, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Op {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct CpuState {
+    pc: usize,
+    acc: i64,
+}
+
+fn run_program(program: &[Op]) -> Result<(CpuState, Vec<usize>), (CpuState, Vec<usize>)> {
+    let mut state = CpuState::default();
+    let mut trace = vec![0usize; program.len()];
+    let exit_pc = program.len();
+    loop {
+        if state.pc == exit_pc {
+            return Ok((state, trace));
+        }
+        if trace[state.pc] != 0 {
+            return Err((state, trace));
+        }
+        trace[state.pc] += 1;
+        state = execute_op(state, &program[state.pc]);
+    }
+}
+
+fn execute_op(cpu: CpuState, op: &Op) -> CpuState {
+    match op {
+        Op::Nop(_) => CpuState {
+            pc: pc_offset(cpu.pc, 1),
+            acc: cpu.acc,
+        },
+        Op::Acc(n) => CpuState {
+            pc: pc_offset(cpu.pc, 1),
+            acc: cpu.acc + n,
+        },
+        Op::Jmp(n) => CpuState {
+            pc: pc_offset(cpu.pc, *n),
+            acc: cpu.acc,
+        },
+    }
+}
+
+fn pc_offset(pc: usize, offset: i64) -> usize {
+    ((pc as i64) + offset) as usize
+}
+
+fn parse_op(line: &str) -> Result<Op, Unexpected<'_>> {
+    let (op_name, op_arg) = line.split_once(' ')
+        .ok_or_else(|| Unexpected::new(line, "an operation and argument"))?;
+    match op_name {
+        "nop" => Ok(Op::Nop(number(op_arg)?)),
+        "acc" => Ok(Op::Acc(number(op_arg)?)),
+        "jmp" => Ok(Op::Jmp(number(op_arg)?)),
+        _ => Err(Unexpected::new(op_name, "nop, acc or jmp")),
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Handheld Halting";
+
+    type Input = Vec<Op>;
+    type Output = Answer<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_op).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(program: &Vec<Op>) -> Answer<i64> {
+        a::acc_before_loop(program)
+    }
+
+    fn part2(program: &Vec<Op>) -> Answer<i64> {
+        b::acc_after_fix(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day08::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 1: expected nop, acc or jmp, found \"mul\"", error("nop +0\nmul +1\n"));
+        assert_eq!("line 1, column 5: expected a number, found \"+x\"", error("jmp +x\n"));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let terminates = Day08::parse("nop +0\nacc +1\n").unwrap();
+        assert_eq!(Answer::None("the program terminates without running an instruction twice"), Day08::part1(&terminates));
+        assert_eq!(Answer::None("the program terminates without a fix"), Day08::part2(&terminates));
+        let unfixable = Day08::parse("acc +1\njmp -1\nacc +1\njmp -1\n").unwrap();
+        assert_eq!(Answer::Found(1), Day08::part1(&unfixable));
+        assert_eq!(Answer::None("no flipped nop or jmp makes the program terminate"), Day08::part2(&unfixable));
+    }
+}
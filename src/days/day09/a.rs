@@ -0,0 +1,47 @@
+use crate::solution::Answer;
+
+use super::find_first_invalid;
+
+/// The first number that is not the sum of two of the `window_size` numbers before it.
+pub fn first_invalid(window_size: usize, numbers: &[usize]) -> Answer<usize> {
+    let invalid = find_first_invalid(window_size, numbers).map(|(_, n)| n);
+    Answer::from_option(invalid, "every number is the sum of two of those before it")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{number, parse_lines};
+
+    use super::*;
+
+    const EXAMPLE: (usize, &str) = (5, "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+");
+
+    #[test]
+    fn test_xmas() {
+        let (window_size, s) = &EXAMPLE;
+        let window_size = *window_size;
+        let numbers = parse_lines(s, number).unwrap();
+
+        assert_eq!(Answer::Found(127), first_invalid(window_size, &numbers));
+    }
+}
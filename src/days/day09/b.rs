@@ -0,0 +1,62 @@
+use std::cmp::Ordering;
+
+use crate::solution::Answer;
+
+use super::find_first_invalid;
+
+pub fn find_encryption_weakness(window_size: usize, numbers: &[usize]) -> Answer<usize> {
+    let (end, v) = match find_first_invalid(window_size, numbers) {
+        Some(invalid) => invalid,
+        None => return Answer::None("every number is the sum of two of those before it"),
+    };
+    for i in (1..end).rev() {
+        for j in (0..i).rev() {
+            let slice = &numbers[j..i];
+            let sum = slice.iter().sum::<usize>();
+            match v.cmp(&sum) {
+                Ordering::Less => break,
+                Ordering::Equal => return Answer::Found(slice.iter().min().unwrap() + slice.iter().max().unwrap()),
+                Ordering::Greater => ()
+            }
+        }
+    }
+    Answer::None("no contiguous numbers add up to the first invalid one")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{number, parse_lines};
+
+    use super::*;
+
+    const EXAMPLE: (usize, &str) = (5, "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+");
+
+    #[test]
+    fn test_xmas() {
+        let (window_size, s) = &EXAMPLE;
+        let window_size = *window_size;
+        let numbers = parse_lines(s, number).unwrap();
+
+        assert_eq!(Answer::Found(62), find_encryption_weakness(window_size, &numbers));
+    }
+}
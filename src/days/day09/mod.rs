@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+
+use crate::solution::{number, parse_lines, Answer, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// How many of the preceding numbers each number may be the sum of two of.
+const WINDOW_SIZE: usize = 25;
+
+/// The position and value of the first number that is not the sum of two of the `window_size`
+/// numbers before it, if any is.
+fn find_first_invalid(window_size: usize, numbers: &[usize]) -> Option<(usize, usize)> {
+    let mut v: BTreeMap<usize, usize> = BTreeMap::new();
+    numbers.get(0..window_size)?.iter()
+        .for_each(|n| *v.entry(*n).or_default() += 1);
+
+    for i in window_size .. numbers.len() {
+        let window_start = i - window_size;
+
+        let next = numbers[i];
+
+        if !is_valid(next, &v) {
+            return Some((i, next));
+        }
+
+        *v.entry(next)
+            .or_default() += 1;
+        match v.entry(numbers[window_start]) {
+            Entry::Occupied(o) if *o.get() == 1 => { o.remove(); },
+            Entry::Occupied(o) => *o.into_mut() -= 1,
+            _ => unreachable!()
+        }
+    }
+    None
+}
+
+fn is_valid(n: usize, v: &BTreeMap<usize, usize>) -> bool {
+    for k in v.keys() {
+        if n < *k {
+            return false;
+        }
+        if v.contains_key(&(n - k)) {
+            return true
+        }
+    }
+    false
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Encoding Error";
+
+    type Input = Vec<usize>;
+    type Output = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, number)
+            .and_then(|numbers: Vec<usize>| if numbers.len() < WINDOW_SIZE {
+                Err(Unexpected::end_of(input, format!("at least {} numbers", WINDOW_SIZE)))
+            } else {
+                Ok(numbers)
+            })
+            .map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Answer<usize> {
+        a::first_invalid(WINDOW_SIZE, numbers)
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Answer<usize> {
+        b::find_encryption_weakness(WINDOW_SIZE, numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preamble() {
+        let input = (1..=24).map(|n| format!("{}\n", n)).collect::<String>();
+        assert_eq!("line 25, column 1: expected at least 25 numbers, found nothing", Day09::parse(&input).unwrap_err().to_string());
+        assert_eq!("line 1, column 1: expected at least 25 numbers, found nothing", Day09::parse("").unwrap_err().to_string());
+
+        let valid = format!("{}25\n26\n49\n", input);
+        let numbers = Day09::parse(&valid).unwrap();
+        assert_eq!(Answer::None("every number is the sum of two of those before it"), Day09::part1(&numbers));
+        assert_eq!(Answer::None("every number is the sum of two of those before it"), Day09::part2(&numbers));
+        let numbers = Day09::parse(&format!("{}25\n1000\n", input)).unwrap();
+        assert_eq!(Answer::Found(1000), Day09::part1(&numbers));
+        assert_eq!(Answer::None("no contiguous numbers add up to the first invalid one"), Day09::part2(&numbers));
+    }
+}
@@ -0,0 +1,14 @@
+pub fn find_jolt_differential(adapters: &[usize]) -> (usize, usize) {
+    let mut adapters = adapters.to_vec();
+    adapters.sort_unstable();
+
+    let (_, ones, threes) = adapters.iter()
+        .fold((0, 0, 1), |(prev_j, ones, threes), j| {
+            match j - prev_j {
+                1 => (*j, ones + 1, threes),
+                3 => (*j, ones, threes + 1),
+                _ => (*j, ones, threes)
+            }
+        });
+    (ones, threes)
+}
@@ -0,0 +1,64 @@
+pub fn find_adapter_combinations(adapters: &[usize]) -> usize {
+    let mut adapters = adapters.to_vec();
+    adapters.sort_unstable();
+    adapters.push(adapters.last().unwrap() + 3);
+
+    let mut memo: Vec<Option<usize>> = vec![None; adapters.len()];
+    memo[adapters.len() - 1] = Some(1);
+    fn combinations(in_jolt: usize, adapters: &[usize], memo: &mut[Option<usize>]) -> usize {
+        if let Some(n) = memo.first().unwrap() {
+            *n
+        } else {
+            match adapters {
+                [head1, head2, head3, ..] if head3 - in_jolt <= 3 => {
+                    let c =
+                        combinations(*head1, &adapters[1..], &mut memo[1..]) +
+                            combinations(*head2, &adapters[2..], &mut memo[2..]) +
+                            combinations(*head3, &adapters[3..], &mut memo[3..]);
+                    memo[0] = Some(c);
+                    c
+                }
+                [head1, head2, ..] if head2 - in_jolt <= 3 => {
+                    let c =
+                        combinations(*head1, &adapters[1..], &mut memo[1..]) +
+                            combinations(*head2, &adapters[2..], &mut memo[2..]);
+                    memo[0] = Some(c);
+                    c
+                }
+                [head1, ..] if head1 - in_jolt <= 3 => {
+                    let c =
+                        combinations(*head1, &adapters[1..], &mut memo[1..]);
+                    memo[0] = Some(c);
+                    c
+                }
+                _ => unreachable!()
+            }
+        }
+    }
+
+    combinations(0, &adapters, &mut memo)
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod benches {
+    extern crate test;
+
+    use std::io::Read;
+    use test::bench::Bencher;
+
+    use crate::days::day10::Day10;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    #[bench]
+    fn benchmark(bencher: &mut Bencher) {
+        let mut f = std::fs::File::open("src/bin/aoc10.txt").unwrap();
+        let mut input = String::new();
+        f.read_to_string(&mut input).unwrap();
+
+        let adapters = Day10::parse(&input).unwrap();
+
+        bencher.iter(|| find_adapter_combinations(&adapters));
+    }
+}
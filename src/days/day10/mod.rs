@@ -0,0 +1,59 @@
+use crate::solution::{number, parse_lines, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// The adapters, which must chain from the outlet without a step of more than 3 jolts.
+fn parse(input: &str) -> Result<Vec<usize>, Unexpected<'_>> {
+    let adapters = parse_lines(input, |line| number(line).map(|n| (n, line.trim())))?;
+    if adapters.is_empty() {
+        return Err(Unexpected::end_of(input, "an adapter"));
+    }
+    let mut chain = adapters.clone();
+    chain.sort_unstable();
+    let mut jolts = 0;
+    for (n, line) in chain {
+        if n > jolts + 3 {
+            return Err(Unexpected::new(line, format!("an adapter of at most {} jolts", jolts + 3)));
+        }
+        jolts = n;
+    }
+    Ok(adapters.into_iter().map(|(n, _)| n).collect())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    type Input = Vec<usize>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(adapters: &Vec<usize>) -> usize {
+        let (ones, threes) = a::find_jolt_differential(adapters);
+        ones * threes
+    }
+
+    fn part2(adapters: &Vec<usize>) -> usize {
+        b::find_adapter_combinations(adapters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day10::parse(input).unwrap_err().to_string();
+        assert_eq!("line 1, column 1: expected an adapter, found nothing", error(""));
+        assert_eq!("line 2, column 1: expected an adapter of at most 7 jolts, found \"8\"", error("1\n8\n4\n12\n"));
+        assert_eq!("line 1, column 1: expected an adapter of at most 3 jolts, found \"4\"", error("4\n"));
+        assert_eq!(Ok(vec![3, 1, 4]), Day10::parse("3\n1\n4\n"));
+    }
+}
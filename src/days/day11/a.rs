@@ -0,0 +1,87 @@
+use super::{Layout, Tile};
+
+/// One round where a seat empties when four or more adjacent seats are occupied.
+pub fn simulate(grid: &Layout) -> Layout {
+    grid.map(|(x, y), tile| if *tile == Tile::Floor {
+        Tile::Floor
+    } else {
+        match grid.neighbours8(x, y).filter(|t| **t == Tile::OccupiedSeat).count() {
+            0 => Tile::OccupiedSeat,
+            4 ..= 8 => Tile::EmptySeat,
+            _ => *tile
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day11::Day11;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    const INPUT1: &str = include_str!("../../../tests/fixtures/day11/example.txt");
+
+    const OUTPUTS: [&str; 5] = ["#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##
+",
+    "#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##",
+    "#.##.L#.##
+#L###LL.L#
+L.#.#..#..
+#L##.##.L#
+#.##.LL.LL
+#.###L#.##
+..#.#.....
+#L######L#
+#.LL###L.L
+#.#L###.##",
+    "#.#L.L#.##
+#LLL#LL.L#
+L.L.L..#..
+#LLL.##.L#
+#.LL.LL.LL
+#.LL#L#.##
+..L.L.....
+#L#LLLL#L#
+#.LLLLLL.L
+#.#L#L#.##",
+    "#.#L.L#.##
+#LLL#LL.L#
+L.#.L..#..
+#L##.##.L#
+#.#L.LL.LL
+#.#L#L#.##
+..L.L.....
+#L#L##L#L#
+#.LLLLLL.L
+#.#L#L#.##"];
+
+    #[test]
+    fn test_simulation() {
+        let mut grid = Day11::parse(INPUT1).unwrap();
+        for output in &OUTPUTS {
+            eprintln!("{}", grid);
+            grid = simulate(&grid);
+            let o = Day11::parse(output).unwrap();
+            assert_eq!(o, grid);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::grid::ADJACENT;
+
+use super::{Layout, Tile};
+
+fn occupied_neighbors(grid: &Layout, x: usize, y: usize) -> usize {
+    ADJACENT.iter()
+        .filter(|&&direction| grid.ray(x, y, direction).find(|t| **t != Tile::Floor) == Some(&Tile::OccupiedSeat))
+        .count()
+}
+
+/// One round where a seat empties when five or more of the first seats visible from it are occupied.
+pub fn simulate(grid: &Layout) -> Layout {
+    grid.map(|(x, y), tile| if *tile == Tile::Floor {
+        Tile::Floor
+    } else {
+        match occupied_neighbors(grid, x, y) {
+            0 => Tile::OccupiedSeat,
+            5..=8 => Tile::EmptySeat,
+            _ => *tile
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day11::Day11;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    const INPUT1: &str = include_str!("../../../tests/fixtures/day11/example.txt");
+
+    const OUTPUTS: [&str; 6] = ["#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##",
+        "#.LL.LL.L#
+#LLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLLL.L
+#.LLLLL.L#",
+        "#.L#.##.L#
+#L#####.LL
+L.#.#..#..
+##L#.##.##
+#.##.#L.##
+#.#####.#L
+..#.#.....
+LLL####LL#
+#.L#####.L
+#.L####.L#",
+        "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##LL.LL.L#
+L.LL.LL.L#
+#.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLL#.L
+#.L#LL#.L#",
+        "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.#L.L#
+#.L####.LL
+..#.#.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#",
+    "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.LL.L#
+#.LLLL#.LL
+..#.L.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#"];
+
+    #[test]
+    fn test_simulation() {
+        let mut grid = Day11::parse(INPUT1).unwrap();
+        for output in &OUTPUTS {
+            eprintln!("{}", grid);
+            grid = simulate(&grid);
+            let o = Day11::parse(output).unwrap();
+            assert_eq!(o, grid);
+        }
+    }
+
+    #[test]
+    fn test_occupied_neighbors_1() {
+        let grid = Day11::parse(".......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....").unwrap();
+        assert_eq!(8, occupied_neighbors(&grid, 3, 4));
+    }
+
+    #[test]
+    fn test_occupied_neighbors_2() {
+        let grid = Day11::parse(".............
+.L.L.#.#.#.#.
+.............").unwrap();
+        assert_eq!(0, occupied_neighbors(&grid, 1, 1));
+    }
+
+    #[test]
+    fn test_occupied_neighbors_3() {
+        let grid = Day11::parse(".##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.").unwrap();
+        assert_eq!(Tile::EmptySeat, grid[(3, 3)]);
+        assert_eq!(0, occupied_neighbors(&grid, 3, 3));
+    }
+
+    #[cfg(feature = "bench")]
+    mod benches {
+        extern crate test;
+
+        use std::io::Read;
+        use test::bench::Bencher;
+
+        use super::*;
+
+        #[bench]
+        fn benchmark(bencher: &mut Bencher) {
+            let mut f = std::fs::File::open("src/bin/aoc11.txt").unwrap();
+            let mut input = String::new();
+            f.read_to_string(&mut input).unwrap();
+            bencher.iter(move || Day11::parse(&input).unwrap().steady_state(simulate));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter, Write};
+
+use crate::grid::{Edge, Grid};
+use crate::solution::{ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Tile::Floor => '.',
+            Tile::EmptySeat => 'L',
+            Tile::OccupiedSeat => '#',
+        })
+    }
+}
+
+/// The seat layout, surrounded by floor so that every seat has eight neighbours.
+pub type Layout = Grid<Tile>;
+
+fn parse(input: &str) -> Result<Layout, Unexpected<'_>> {
+    let layout = Grid::parse(input, "'.', 'L' or '#'", |c| match c {
+        '.' => Some(Tile::Floor),
+        'L' => Some(Tile::EmptySeat),
+        '#' => Some(Tile::OccupiedSeat),
+        _ => None,
+    })?;
+    Ok(layout.with_edge(Edge::Padded(Tile::Floor)))
+}
+
+impl Grid<Tile> {
+    fn occupied_seats(&self) -> usize {
+        self.cells()
+            .filter(|tile| **tile == Tile::OccupiedSeat)
+            .count()
+    }
+
+    /// Applies `simulate` until nothing changes, and counts the occupied seats.
+    fn steady_state(&self, simulate: fn(&Layout) -> Layout) -> usize {
+        let mut layout = self.clone();
+        loop {
+            let next_layout = simulate(&layout);
+            if next_layout == layout {
+                break;
+            }
+            layout = next_layout;
+        }
+        layout.occupied_seats()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Seating System";
+
+    type Input = Layout;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(grid: &Layout) -> usize {
+        grid.steady_state(a::simulate)
+    }
+
+    fn part2(grid: &Layout) -> usize {
+        grid.steady_state(b::simulate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day11::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 1: expected a row of 3 tiles, found \"LL\"", error("L.L\nLL\n"));
+        assert_eq!("line 1, column 3: expected '.', 'L' or '#', found \"x\"", error("L.x\n"));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        assert_eq!("L.#\n#LL\n", Day11::parse("L.#\n#LL\n").unwrap().to_string());
+    }
+}
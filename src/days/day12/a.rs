@@ -0,0 +1,50 @@
+use super::Instruction;
+
+/// The Manhattan distance the ship ends up from where it started.
+pub fn distance(instructions: &[Instruction]) -> isize {
+    instructions.iter()
+        .copied()
+        .fold(Ship::new(), Ship::apply)
+        .distance()
+}
+
+#[derive(Copy, Clone)]
+struct Ship {
+    heading: isize,
+    x: isize,
+    y: isize,
+}
+
+impl Ship {
+    fn new() -> Self {
+        Self {
+            heading: 90,
+            x: 0,
+            y: 0
+        }
+    }
+
+    fn apply(self, instruction: Instruction) -> Self {
+        match instruction {
+            Instruction::North(distance) => Self { y: self.y + distance, .. self },
+            Instruction::South(distance) => Self { y: self.y - distance, .. self },
+            Instruction::East(distance) => Self { x: self.x + distance, .. self },
+            Instruction::West(distance) => Self { x: self.x - distance, .. self },
+            Instruction::TurnLeft(turn_by) => Self { heading: (self.heading - turn_by).rem_euclid(360), .. self },
+            Instruction::TurnRight(turn_by) => Self { heading: (self.heading + turn_by).rem_euclid(360), .. self },
+            Instruction::Forward(distance) => {
+                match self.heading {
+                    0 => Self { y: self.y + distance, .. self },
+                    90 => Self { x: self.x + distance, .. self },
+                    180 => Self { y: self.y - distance, .. self },
+                    270 => Self { x: self.x - distance, .. self },
+                    _ => unreachable!()
+                }
+            },
+        }
+    }
+
+    fn distance(self) -> isize {
+        self.x.abs() + self.y.abs()
+    }
+}
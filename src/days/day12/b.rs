@@ -0,0 +1,54 @@
+use super::Instruction;
+
+/// The Manhattan distance the ship ends up from where it started.
+pub fn distance(instructions: &[Instruction]) -> isize {
+    instructions.iter()
+        .copied()
+        .fold(Ship::new(), Ship::apply)
+        .distance()
+}
+
+#[derive(Copy, Clone)]
+struct Ship {
+    x: isize,
+    y: isize,
+    wx: isize,
+    wy: isize
+}
+
+impl Ship {
+    fn new() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            wx: 10,
+            wy: 1,
+        }
+    }
+
+    fn turn(self, turn_by: isize) -> Self {
+        match turn_by.rem_euclid(360) {
+            0 => self,
+            90 => Self { wx: self.wy, wy: -self.wx, .. self },
+            180 => Self { wx: -self.wx, wy: -self.wy, .. self },
+            270 => Self { wx: -self.wy, wy: self.wx, .. self },
+            _ => unreachable!()
+        }
+    }
+
+    fn apply(self, instruction: Instruction) -> Self {
+        match instruction {
+            Instruction::North(distance) => Self { wy: self.wy + distance, .. self },
+            Instruction::South(distance) => Self { wy: self.wy - distance, .. self },
+            Instruction::East(distance) => Self { wx: self.wx + distance, .. self },
+            Instruction::West(distance) => Self { wx: self.wx - distance, .. self },
+            Instruction::TurnLeft(turn_by) => self.turn(-turn_by),
+            Instruction::TurnRight(turn_by) => self.turn(turn_by),
+            Instruction::Forward(distance) => Self { x: self.x + self.wx * distance, y: self.y + self.wy * distance, .. self },
+        }
+    }
+
+    fn distance(self) -> isize {
+        self.x.abs() + self.y.abs()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::solution::{number, parse_lines, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    North(isize),
+    South(isize),
+    East(isize),
+    West(isize),
+    TurnLeft(isize),
+    TurnRight(isize),
+    Forward(isize),
+}
+
+fn parse_instruction(i: &str) -> Result<Instruction, Unexpected<'_>> {
+    let action = i.chars().next().ok_or_else(|| Unexpected::end_of(i, "an instruction"))?;
+    let (action_text, n_text) = i.split_at(action.len_utf8());
+    let n = number(n_text)?;
+    match action {
+        'L' | 'R' if n % 90 != 0 => Err(Unexpected::new(n_text, "a turn by a multiple of 90 degrees")),
+        'N' => Ok(Instruction::North(n)),
+        'S' => Ok(Instruction::South(n)),
+        'E' => Ok(Instruction::East(n)),
+        'W' => Ok(Instruction::West(n)),
+        'L' => Ok(Instruction::TurnLeft(n)),
+        'R' => Ok(Instruction::TurnRight(n)),
+        'F' => Ok(Instruction::Forward(n)),
+        _ => Err(Unexpected::new(action_text, "one of N, S, E, W, L, R or F")),
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Rain Risk";
+
+    type Input = Vec<Instruction>;
+    type Output = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_instruction).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> isize {
+        a::distance(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> isize {
+        b::distance(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day12::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 2: expected a turn by a multiple of 90 degrees, found \"45\"", error("F10\nR45\n"));
+        assert_eq!("line 1, column 1: expected one of N, S, E, W, L, R or F, found \"X\"", error("X1\n"));
+        assert_eq!("line 2, column 1: expected an instruction, found nothing", error("F10\n\nF1\n"));
+    }
+}
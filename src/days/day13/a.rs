@@ -0,0 +1,17 @@
+use super::Notes;
+
+/// The id of the first bus to leave after the earliest departure, times the minutes to wait for it.
+pub fn earliest_bus(notes: &Notes) -> usize {
+    let t = notes.earliest;
+    let mut bus_id = 0;
+    let mut t2 = usize::MAX;
+    for b in notes.buses.iter().flatten().copied() {
+        let c = ((t + b) / b) * b - t;
+
+        if c < t2 {
+            t2 = c;
+            bus_id = b;
+        }
+    }
+    bus_id * t2
+}
@@ -0,0 +1,43 @@
+use super::Notes;
+
+fn buses(notes: &Notes) -> impl Iterator<Item=(isize, isize)> + '_ {
+    notes.buses.iter()
+        .enumerate()
+        .filter_map(|(i, b)| b.map(|b| (b as isize, i as isize)))
+}
+
+fn next_ts(ts: isize, step: usize, mod_shift: isize, modulo: isize) -> isize {
+    (ts ..).step_by(step)
+        .find(|v| (v + mod_shift) % modulo == 0)
+        .unwrap()
+}
+
+/// The earliest timestamp at which each bus leaves as many minutes later as its position in the list.
+pub fn earliest_timestamp(notes: &Notes) -> usize {
+    let mut buses = buses(notes);
+    let (first_bus_id, first_offset) = buses.next().unwrap();
+    let (ts, _) = buses
+        .fold((0, first_bus_id), |(ts, n), (bus_id, offset)| (next_ts(ts, n as usize, offset - first_offset, bus_id), n * bus_id));
+    (ts - first_offset) as usize
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod benches {
+    extern crate test;
+
+    use std::io::Read;
+    use test::bench::Bencher;
+
+    use crate::days::day13::Day13;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    #[bench]
+    fn benchmark(bencher: &mut Bencher) {
+        let mut f = std::fs::File::open("src/bin/aoc13.txt").unwrap();
+        let mut input = String::new();
+        f.read_to_string(&mut input).unwrap();
+        bencher.iter(move || earliest_timestamp(&Day13::parse(&input).unwrap()));
+    }
+}
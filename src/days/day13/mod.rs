@@ -0,0 +1,69 @@
+use crate::solution::{number, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// The earliest departure and the bus ids, `None` for buses that are out of service.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Notes {
+    pub earliest: usize,
+    pub buses: Vec<Option<usize>>,
+}
+
+fn parse(input: &str) -> Result<Notes, Unexpected<'_>> {
+    let mut lines = input.lines();
+    let earliest = number(lines.next().unwrap_or(input))?;
+    let line = lines.next()
+        .ok_or_else(|| Unexpected::end_of(input, "a list of bus ids"))?;
+    let buses = line
+        .split(',')
+        .map(|b| match b {
+            "x" => Ok(None),
+            _ => match number(b)? {
+                0 => Err(Unexpected::new(b.trim(), "a bus id above 0")),
+                id => Ok(Some(id)),
+            },
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if buses.iter().all(Option::is_none) {
+        return Err(Unexpected::new(line, "at least one bus in service"));
+    }
+    Ok(Notes { earliest, buses })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Shuttle Search";
+
+    type Input = Notes;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(notes: &Notes) -> usize {
+        a::earliest_bus(notes)
+    }
+
+    fn part2(notes: &Notes) -> usize {
+        b::earliest_timestamp(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Notes { earliest: 939, buses: vec![Some(7), Some(13), None, Some(59)] }),
+                   Day13::parse("939\n7,13,x,59\n"));
+        assert_eq!("line 2, column 3: expected a number, found \"y\"", Day13::parse("939\n7,y\n").unwrap_err().to_string());
+        assert_eq!("line 2, column 1: expected a list of bus ids, found nothing", Day13::parse("939\n").unwrap_err().to_string());
+        assert_eq!("line 2, column 3: expected a bus id above 0, found \"0\"", Day13::parse("939\n7,0\n").unwrap_err().to_string());
+        assert_eq!("line 2, column 1: expected at least one bus in service, found \"x,x\"", Day13::parse("939\nx,x\n").unwrap_err().to_string());
+    }
+}
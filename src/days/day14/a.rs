@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::fmt::{Formatter, Debug};
+
+use super::{Bitmask, Instruction, MAX};
+
+/// The sum of memory after writing each value through the current mask.
+pub fn run(program: &[Instruction]) -> u64 {
+    let mut mask = Mask::default();
+    let mut mem: HashMap<u64, Value> = HashMap::new();
+    program.iter()
+        .for_each(|i| apply(i, &mut mask, &mut mem));
+    mem.values().map(|v| v.0)
+        .sum::<u64>()
+}
+
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+struct Value(u64);
+
+impl Value {
+    #[cfg(test)]
+    fn new_from_binary(s: &str) -> Self {
+        Self(u64::from_str_radix(s, 2).unwrap() & MAX)
+    }
+}
+
+impl Debug for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:036b} ({})", self.0, self.0))
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug)]
+struct Mask(u64, u64);
+
+impl Mask {
+    fn new(mask: &Bitmask) -> Self {
+        Self(mask.floating & MAX, mask.ones & MAX)
+    }
+
+    fn apply(&self, v: Value) -> Value {
+        Value(v.0 & self.0 | self.1)
+    }
+}
+
+fn apply(instruction: &Instruction, mask: &mut Mask, mem: &mut HashMap<u64, Value>) {
+    match instruction {
+        Instruction::Mask(m) => *mask = Mask::new(m),
+        Instruction::Write(address, value) => {
+            mem.insert(*address, mask.apply(Value(*value)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day14::Bitmask;
+
+    use super::*;
+
+    const MASK_EXAMPLES: [(&str, &str, &str); 3] = [
+        ("000000000000000000000000000000001011",
+         "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+         "000000000000000000000000000001001001"),
+        ("000000000000000000000000000001100101",
+         "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+         "000000000000000000000000000001100101"),
+        ("000000000000000000000000000000000000",
+         "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+         "000000000000000000000000000001000000"),
+    ];
+
+    #[test]
+    fn test_mask() {
+        for (value, mask, expected) in &MASK_EXAMPLES {
+            assert_eq!(Value::new_from_binary(expected),
+                       Mask::new(&Bitmask::parse(mask).unwrap())
+                           .apply(Value::new_from_binary(value)));
+
+        }
+    }
+
+}
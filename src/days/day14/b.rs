@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use super::{Bitmask, Instruction, MAX};
+
+/// The sum of memory after writing each value to every address the current mask decodes to.
+pub fn run(program: &[Instruction]) -> u64 {
+    let mut mask = Mask::default();
+    let mut mem: HashMap<u64, Value> = HashMap::new();
+    program.iter()
+        .for_each(|i| apply(i, &mut mask, &mut mem));
+    mem.values().map(|v| v.0)
+        .sum::<u64>()
+}
+
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+struct Value(u64);
+
+impl Debug for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:036b} ({})", self.0, self.0))
+    }
+}
+
+#[derive(Clone, Default)]
+struct Mask(u64, u64, Vec<u64>);
+
+impl Debug for Mask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("or: {:36b}, and: {:36b} floating: {:?}", self.0, self.1, self.2))
+    }
+}
+
+impl Mask {
+    fn new(mask: &Bitmask) -> Self {
+        let floating = Self::bits(mask.floating & MAX)
+            .map(|b| 1 << b)
+            .collect();
+        Self(mask.ones & MAX, !mask.floating & MAX, floating)
+    }
+
+    fn bits(mut n: u64) -> impl Iterator<Item=usize> {
+        std::iter::from_fn(move || {
+            if n != 0 {
+                let b = n.trailing_zeros();
+                n ^= 1 << b;
+                Some(b as usize)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn floating(&self) -> impl Iterator<Item=u64> + '_ {
+        (0..1u64 << self.2.len())
+            .map(move |i| Self::bits(i)
+                .fold(0u64, |a, n| a | self.2[n]))
+    }
+    fn apply(&self, address: u64) -> impl Iterator<Item=u64> + '_ {
+        let address = address & self.1 | self.0;
+        self.floating()
+            .map(move |f| address | f)
+    }
+}
+
+fn apply(instruction: &Instruction, mask: &mut Mask, mem: &mut HashMap<u64, Value>) {
+    match instruction {
+        Instruction::Mask(m) => *mask = Mask::new(m),
+        Instruction::Write(address, value) => {
+            mask.apply(*address)
+                .for_each(|a| {
+                    mem.insert(a, Value(*value));
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day14::Bitmask;
+
+    use super::*;
+
+    #[test]
+    fn test_mask() {
+        let mask_examples: [(&str, u64, Vec<u64>); 2] = [
+            ("000000000000000000000000000000X1001X",
+             42,
+             vec![26, 27, 58, 59]),
+            ("00000000000000000000000000000000X0XX",
+             26,
+             vec![16, 17, 18, 19, 24, 25, 26, 27]),
+        ];
+        for (mask, address, expected) in &mask_examples {
+            let mask = Mask::new(&Bitmask::parse(mask).unwrap());
+            let v = mask
+                .apply(*address)
+                .collect::<Vec<_>>();
+            assert_eq!(*expected, v);
+        }
+    }
+}
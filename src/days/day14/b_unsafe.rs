@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use super::{b, Bitmask, Instruction, MAX};
+
+/// As [`super::b::run`], decoding floating bits with `pdep`; on a CPU without BMI2 this is
+/// [`super::b::run`].
+pub fn run(program: &[Instruction]) -> u64 {
+    if !is_x86_feature_detected!("bmi2") {
+        return b::run(program);
+    }
+    let mut mask = Mask::default();
+    let mut mem: HashMap<Address, Value> = HashMap::new();
+    program.iter()
+        .for_each(|i| apply(i, &mut mask, &mut mem));
+    mem.values().map(|v| v.0)
+        .sum::<u64>()
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Value(u64);
+
+impl Debug for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:036b} ({})", self.0, self.0))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Address(u64);
+
+impl Debug for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:x}", self.0))
+    }
+}
+
+
+#[derive(Clone, Copy, Default)]
+struct Mask {
+    or: u64,
+    floating: u64
+}
+
+impl Debug for Mask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("or: {:36b}, floating: {:?}", self.or, self.floating))
+    }
+}
+
+impl Mask {
+    fn new(mask: &Bitmask) -> Self {
+        Self { or: mask.ones & MAX, floating: mask.floating & MAX }
+    }
+
+    fn floating_bits_permutation(&self, n: u64) -> u64 {
+        debug_assert!(is_x86_feature_detected!("bmi2"));
+        // Safety: masks are only applied once `run` has found the CPU has BMI2.
+        unsafe { pdep(n, self.floating) }
+    }
+
+    fn floating_bits(&self) -> impl Iterator<Item=u64> + '_ {
+        (0..1 << self.floating.count_ones())
+            .map(move |i| self.floating_bits_permutation(i))
+    }
+
+    fn apply(&self, address: Address) -> impl Iterator<Item=Address> + '_ {
+        let address = address.0 | self.or;
+        self.floating_bits()
+            .map(move |f| Address(address ^ f))
+    }
+}
+
+#[target_feature(enable = "bmi2")]
+unsafe fn pdep(n: u64, mask: u64) -> u64 {
+    core::arch::x86_64::_pdep_u64(n, mask)
+}
+
+fn apply(instruction: &Instruction, mask: &mut Mask, mem: &mut HashMap<Address, Value>) {
+    match instruction {
+        Instruction::Mask(m) => *mask = Mask::new(m),
+        Instruction::Write(address, value) => {
+            mask.apply(Address(*address))
+                .for_each(|a| {
+                    mem.insert(a, Value(*value));
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use crate::days::day14::{b, Bitmask};
+
+    use super::*;
+
+    /// Masks with up to eight floating bits, to keep the number of decoded addresses small.
+    fn bitmask() -> impl Strategy<Value=Bitmask> {
+        (0..=MAX, vec(0..36u32, 0..8)).prop_map(|(ones, bits)| {
+            let floating = bits.iter().fold(0, |f, b| f | 1 << b);
+            Bitmask { ones: ones & !floating, floating }
+        })
+    }
+
+    fn instruction() -> impl Strategy<Value=Instruction> {
+        prop_oneof![
+            bitmask().prop_map(Instruction::Mask),
+            (prop_oneof![0..64u64, 0..=MAX], 0..=MAX).prop_map(|(a, v)| Instruction::Write(a, v)),
+        ]
+    }
+
+    fn program() -> impl Strategy<Value=Vec<Instruction>> {
+        (bitmask(), vec(instruction(), 0..32)).prop_map(|(mask, rest)| {
+            std::iter::once(Instruction::Mask(mask)).chain(rest).collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn run_matches_b(program in program()) {
+            prop_assert_eq!(b::run(&program), run(&program));
+        }
+    }
+
+    #[test]
+    fn test_mask() {
+        if !is_x86_feature_detected!("bmi2") {
+            return;
+        }
+        let mask_examples: [(&str, u64, Vec<u64>); 2] = [
+            ("000000000000000000000000000000X1001X",
+             42,
+             vec![26, 27, 58, 59]),
+            ("00000000000000000000000000000000X0XX",
+             26,
+             vec![16, 17, 18, 19, 24, 25, 26, 27]),
+        ];
+        for (mask, address, expected) in &mask_examples {
+            let mask = Mask::new(&Bitmask::parse(mask).unwrap());
+
+            assert!(mask
+                .apply(Address(*address))
+                .all(|n| expected.contains(&n.0)));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::solution::{number, parse_lines, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+#[cfg(target_arch = "x86_64")]
+pub mod b_unsafe;
+
+const MAX: u64 = 0b1111_1111_1111_1111_1111_1111_1111_1111_1111u64;
+
+/// A 36 bit mask: the bits set to `1`, and the `X` bits. All other bits are `0`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bitmask {
+    pub ones: u64,
+    pub floating: u64,
+}
+
+impl Bitmask {
+    fn parse(s: &str) -> Result<Self, Unexpected<'_>> {
+        if s.len() != 36 {
+            return Err(Unexpected::new(s, "a mask of 36 bits"));
+        }
+        let mut mask = Bitmask::default();
+        for (i, c) in s.char_indices() {
+            let bit = 1u64 << (s.len() - i - 1);
+            match c {
+                '1' => mask.ones |= bit,
+                '0' => {}
+                'X' => mask.floating |= bit,
+                _ => return Err(Unexpected::new(&s[i..i + c.len_utf8()], "'0', '1' or 'X'")),
+            }
+        }
+        Ok(mask)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Mask(Bitmask),
+    Write(u64, u64),
+}
+
+/// Parses `s` as a number of at most 36 bits.
+fn number_36<'a>(s: &'a str, expected: &str) -> Result<u64, Unexpected<'a>> {
+    match number::<u64>(s)? {
+        n if n > MAX => Err(Unexpected::new(s.trim(), expected)),
+        n => Ok(n),
+    }
+}
+
+fn parse_instruction(s: &str) -> Result<Instruction, Unexpected<'_>> {
+    let (lhs, rhs) = s.split_once(" = ")
+        .ok_or_else(|| Unexpected::new(s, "an assignment"))?;
+    if lhs == "mask" {
+        Ok(Instruction::Mask(Bitmask::parse(rhs)?))
+    } else {
+        let address = lhs.strip_prefix("mem[")
+            .and_then(|a| a.strip_suffix(']'))
+            .ok_or_else(|| Unexpected::new(lhs, "mask or mem[<address>]"))?;
+        Ok(Instruction::Write(number_36(address, "a 36 bit address")?, number_36(rhs, "a 36 bit value")?))
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Docking Data";
+
+    type Input = Vec<Instruction>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_instruction).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(program: &Vec<Instruction>) -> u64 {
+        a::run(program)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> u64 {
+        b::run(program)
+    }
+}
+
+/// Day 14 with the second part decoding floating bits with `pdep`.
+#[cfg(target_arch = "x86_64")]
+pub struct Day14Unsafe;
+
+#[cfg(target_arch = "x86_64")]
+impl Solution for Day14Unsafe {
+    const DAY: u32 = Day14::DAY;
+    const TITLE: &'static str = Day14::TITLE;
+
+    type Input = Vec<Instruction>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Day14::parse(input)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> u64 {
+        Day14::part1(program)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> u64 {
+        b_unsafe::run(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(vec![
+            Instruction::Mask(Bitmask { ones: 0b1000000, floating: MAX & !0b1000010 }),
+            Instruction::Write(8, 11),
+        ]), Day14::parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\n"));
+        let error = |input: &str| Day14::parse(input).unwrap_err().to_string();
+        assert_eq!("line 1, column 43: expected '0', '1' or 'X', found \"Y\"",
+                   error("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0Y\n"));
+        assert_eq!("line 2, column 1: expected mask or mem[<address>], found \"mem(8)\"",
+                   error("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem(8) = 11\n"));
+        assert_eq!("line 1, column 5: expected a number, found \"x\"", error("mem[x] = 11\n"));
+        assert_eq!("line 1, column 5: expected a 36 bit address, found \"68719476736\"", error("mem[68719476736] = 5\n"));
+        assert_eq!("line 1, column 10: expected a 36 bit value, found \"68719476736\"", error("mem[8] = 68719476736\n"));
+    }
+}
@@ -0,0 +1,6 @@
+use super::Game;
+
+pub fn solve(starting: &[usize]) -> usize {
+    let mut game = Game::new(starting);
+    game.until(2020)
+}
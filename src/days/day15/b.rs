@@ -0,0 +1,30 @@
+use super::Game;
+
+pub fn solve(starting: &[usize]) -> usize {
+    let mut game = Game::new(starting);
+    game.until(30000000)
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod benches {
+    extern crate test;
+
+    use std::io::Read;
+    use test::bench::Bencher;
+
+    use crate::days::day15::Day15;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    #[bench]
+    fn bench(bencher: &mut Bencher) {
+        let mut f = std::fs::File::open("src/bin/aoc15.txt").unwrap();
+        let mut input = String::new();
+        f.read_to_string(&mut input).unwrap();
+
+        bencher.iter(move || {
+            solve(&Day15::parse(&input).unwrap())
+        });
+    }
+}
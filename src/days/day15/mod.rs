@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::solution::{number, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// The numbers spoken after the starting numbers.
+#[derive(Default)]
+struct Game {
+    map: HashMap<usize, usize>,
+    last: usize,
+    turn: usize,
+}
+
+impl Iterator for Game {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match self.map.insert(self.last, self.turn) {
+            None => 0,
+            Some(j) => self.turn - j,
+        };
+        self.turn += 1;
+        self.last = next;
+
+        Some(next)
+    }
+}
+
+impl Game {
+    fn until(&mut self, turn: usize) -> usize {
+        assert!(turn > self.turn);
+        self.nth(turn - self.turn - 1).unwrap()
+    }
+
+    fn new(starting: &[usize]) -> Self {
+        let mut init = Self::default();
+
+        starting.iter().enumerate()
+            .map(|(turn, v)| (turn + 1, *v))
+            .for_each(|(turn, v)| {
+                init.map.insert(v, turn);
+                init.last = v;
+                init.turn = turn;
+            });
+        init
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<usize>, Unexpected<'_>> {
+    input.lines().next().unwrap_or(input)
+        .split(',')
+        .map(number)
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Rambunctious Recitation";
+
+    type Input = Vec<usize>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(starting: &Vec<usize>) -> usize {
+        a::solve(starting)
+    }
+
+    fn part2(starting: &Vec<usize>) -> usize {
+        b::solve(starting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let game = Game::new(&Day15::parse("0,3,6").unwrap());
+        assert_eq!(vec![0usize, 3, 3, 1, 0, 4, 0], game.take(7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("line 1, column 5: expected a number, found nothing", Day15::parse("0,3,\n").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::DynBitIndex;
+
+use super::Notes;
+
+/// The sum of the values on nearby tickets that are not valid for any field.
+pub fn error_rate(notes: &Notes) -> usize {
+    Problem::new(notes).error_rate()
+}
+
+#[derive(Debug)]
+struct FieldRule {
+    field: String,
+    valid_values: DynBitIndex,
+}
+
+#[derive(Debug)]
+struct Ticket(Vec<usize>);
+
+#[derive(Debug)]
+struct Problem {
+    fields: Vec<FieldRule>,
+    nearby_tickets: Vec<Ticket>,
+}
+
+impl Problem {
+    fn new(notes: &Notes) -> Self {
+        let fields = notes.fields.iter()
+            .map(|f| FieldRule {
+                field: f.name.clone(),
+                valid_values: f.valid_values(),
+            })
+            .collect();
+        let nearby_tickets = notes.nearby_tickets.iter()
+            .map(|t| Ticket(t.clone()))
+            .collect();
+        Self {
+            fields,
+            nearby_tickets,
+        }
+    }
+
+    fn matching_fields(&self, v: usize) -> impl Iterator<Item=&str> + '_ {
+        self.fields.iter()
+            .filter(move |f| f.valid_values.contains(v))
+            .map(|f| f.field.as_str())
+    }
+
+    fn error_rate(&self) -> usize {
+        self.nearby_tickets.iter()
+            .flat_map(|t| t.0.iter())
+            .filter(|v| self.matching_fields(**v).next().is_none())
+            .sum::<usize>()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use crate::days::day16::{Day16, Field};
+    use crate::solution::Solution;
+    use crate::BitIndex;
+
+    use super::*;
+
+    /// The index the error rate is checked against. Its capacity is well below the values
+    /// [`Day16::parse`] accepts, so every value generated fits.
+    type Reference = BitIndex<8>;
+
+    fn ranges() -> impl Strategy<Value=Vec<RangeInclusive<usize>>> {
+        vec((0..Reference::CAPACITY, 0..300usize)
+            .prop_map(|(start, len)| start..=(start + len).min(Reference::CAPACITY - 1)), 0..4)
+    }
+
+    fn notes() -> impl Strategy<Value=Notes> {
+        let fields = vec(ranges(), 1..6).prop_map(|ranges| ranges.into_iter()
+            .enumerate()
+            .map(|(i, ranges)| Field { name: format!("field {}", i), ranges })
+            .collect::<Vec<_>>());
+        let tickets = (1..6usize).prop_flat_map(|len| (
+            vec(0..Reference::CAPACITY, len),
+            vec(vec(0..Reference::CAPACITY, len), 0..8),
+        ));
+        (fields, tickets)
+            .prop_map(|(fields, (ticket, nearby_tickets))| Notes { fields, ticket, nearby_tickets })
+    }
+
+    #[test]
+    fn test_values_beyond_a_fixed_index() {
+        let notes = Day16::parse("class: 1-3 or 5-2000\n\nyour ticket:\n7\n\nnearby tickets:\n1500\n4\n3000\n").unwrap();
+        assert_eq!(3004, error_rate(&notes));
+    }
+
+    proptest! {
+        #[test]
+        fn error_rate_matches_bit_index(notes in notes()) {
+            let valid = notes.fields.iter()
+                .map(|f| Reference::from_ranges(f.ranges.iter().cloned()))
+                .fold(Reference::empty(), |a, b| a | b);
+            let expected = notes.nearby_tickets.iter()
+                .flatten()
+                .filter(|&&v| !valid.contains(v))
+                .sum::<usize>();
+            prop_assert_eq!(expected, error_rate(&notes));
+        }
+    }
+}
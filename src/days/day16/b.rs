@@ -0,0 +1,174 @@
+use std::fmt::Debug;
+use crate::{Assignment, BitMatrix, BitSet, DynBitIndex};
+use crate::solution::Answer;
+
+use super::{Field, Notes};
+
+/// The product of the values of the fields on your ticket whose names start with `departure`.
+pub fn departure_product(notes: &Notes) -> Answer<usize> {
+    let problem = Problem::new(notes);
+    match problem.map_fields::<DynBitIndex>() {
+        Answer::Found(fields) => Answer::Found(fields.iter()
+            .filter_map(|(a, b)| if a.starts_with("departure") { Some(b) } else { None })
+            .product::<usize>()),
+        Answer::None(why) => Answer::None(why),
+    }
+}
+
+/// How the fields can be assigned to the positions on the tickets.
+pub fn field_assignment(notes: &Notes) -> Assignment {
+    Problem::new(notes).candidates::<DynBitIndex>().assign()
+}
+
+type ValueIndex = DynBitIndex;
+
+#[derive(Clone, Debug)]
+struct FieldRule {
+    id: usize,
+    field: String,
+    valid_values: ValueIndex,
+}
+
+
+impl FieldRule {
+    fn new(id: usize, field: &Field) -> FieldRule {
+        Self {
+            id,
+            field: field.name.clone(),
+            valid_values: field.valid_values(),
+        }
+    }
+}
+
+
+#[derive(Debug)]
+struct Ticket(Vec<usize>);
+
+#[derive(Debug)]
+struct Problem {
+    fields: Vec<FieldRule>,
+    ticket: Ticket,
+    nearby_tickets: Vec<Ticket>,
+}
+
+impl Problem {
+    fn new(notes: &Notes) -> Self {
+        let fields = notes.fields.iter()
+            .enumerate()
+            .map(|(i, f)| FieldRule::new(i, f))
+            .collect();
+        Self {
+            fields,
+            ticket: Ticket(notes.ticket.clone()),
+            nearby_tickets: notes.nearby_tickets.iter().map(|t| Ticket(t.clone())).collect(),
+        }
+    }
+
+    fn matching_fields(&self, v: usize) -> impl Iterator<Item=&FieldRule> + '_ {
+        self.fields.iter()
+            .filter(move |f| f.valid_values.contains(v))
+    }
+
+    fn valid_ticket(&self, ticket: &Ticket) -> bool {
+        ticket.0.iter()
+            .all(|v| self.matching_fields(*v).next().is_some())
+    }
+
+    fn matching_field_index<F: BitSet>(&self, v: usize) -> F {
+        self.matching_fields(v)
+            .fold(F::with_capacity(self.fields.len()), |mut i, f| { i += f.id; i})
+    }
+
+    fn candidates<F: BitSet>(&self) -> BitMatrix {
+        let valid_tickets = self.nearby_tickets.iter()
+            .filter(|t| self.valid_ticket(t))
+            .collect::<Vec<_>>();
+
+        let valid_tickets = valid_tickets.into_iter()
+            .map(|t| t.0.iter().map(|v| self.matching_field_index::<F>(*v)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let ticket_fields = self.ticket.0.len();
+        let possible_fields = self.fields.len();
+        let mut candidates = vec![F::from_ranges(std::iter::once(..possible_fields)); ticket_fields];
+
+        for t in &valid_tickets {
+            for i in 0..ticket_fields {
+                candidates[i] &= &t[i]
+            }
+        }
+
+        BitMatrix::from_rows(possible_fields, candidates.iter().map(|c| c.iter()))
+    }
+
+    fn map_fields<F: BitSet>(&self) -> Answer<Vec<(&str, usize)>> {
+        match self.candidates::<F>().assign() {
+            Assignment::Unique(fields) => Answer::Found(fields.into_iter()
+                .enumerate()
+                .map(|(i, j)| (self.fields[j].field.as_str(), self.ticket.0[i]))
+                .collect()),
+            Assignment::Ambiguous { .. } => Answer::None("the fields can be mapped more than one way"),
+            Assignment::Infeasible { .. } => Answer::None("no mapping fits every ticket"),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::BitIndex;
+    use crate::days::day16::Day16;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    const EXAMPLE: &str = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9
+";
+
+    #[test]
+    fn test_index_representations_agree() {
+        let problem = Problem::new(&Day16::parse(EXAMPLE).unwrap());
+        let expected = Answer::Found(vec![("row", 11), ("class", 12), ("seat", 13)]);
+        assert_eq!(expected, problem.map_fields::<BitIndex<1>>());
+        assert_eq!(expected, problem.map_fields::<DynBitIndex>());
+    }
+
+    #[test]
+    fn test_forced_fields_when_elimination_stalls() {
+        let problem = Problem::new(&Day16::parse("a: 0-9
+b: 0-9
+c: 0-19
+d: 10-29
+
+your ticket:
+1,2,3,4
+
+nearby tickets:
+1,2,15,25
+2,1,5,15
+").unwrap());
+        let expected = Assignment::Ambiguous {
+            forced: vec![None, None, Some(2), Some(3)],
+            matching: vec![0, 1, 2, 3],
+        };
+        assert_eq!(expected, problem.candidates::<DynBitIndex>().assign());
+    }
+
+    #[test]
+    fn test_no_unique_mapping() {
+        let ambiguous = Day16::parse("a: 0-9\nb: 0-9\n\nyour ticket:\n1,2\n\nnearby tickets:\n3,4\n").unwrap();
+        assert_eq!(Answer::None("the fields can be mapped more than one way"), departure_product(&ambiguous));
+        let infeasible = Day16::parse("a: 0-9\nb: 10-19\n\nyour ticket:\n1,2\n\nnearby tickets:\n3,4\n").unwrap();
+        assert_eq!(Answer::None("no mapping fits every ticket"), departure_product(&infeasible));
+    }
+}
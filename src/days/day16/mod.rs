@@ -0,0 +1,156 @@
+use std::ops::RangeInclusive;
+
+use crate::DynBitIndex;
+use crate::solution::{number, Answer, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// A ticket field and the ranges of values valid for it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub ranges: Vec<RangeInclusive<usize>>,
+}
+
+impl Field {
+    /// The values valid for the field.
+    ///
+    /// # Panics
+    ///
+    /// If a range ends at or beyond [`DynBitIndex::PARSE_CAPACITY`], which parsing rules out.
+    pub fn valid_values(&self) -> DynBitIndex {
+        DynBitIndex::try_from_ranges_within(self.ranges.iter().cloned(), DynBitIndex::PARSE_CAPACITY)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Notes {
+    pub fields: Vec<Field>,
+    pub ticket: Vec<usize>,
+    pub nearby_tickets: Vec<Vec<usize>>,
+}
+
+fn parse_field(s: &str) -> Result<Field, Unexpected<'_>> {
+    fn range(s: &str) -> Result<RangeInclusive<usize>, Unexpected<'_>> {
+        let (from, to) = s.split_once('-')
+            .ok_or_else(|| Unexpected::new(s, "a range like 1-3"))?;
+        let range = number(from)?..=number(to)?;
+        if range.is_empty() {
+            return Err(Unexpected::new(s, "a range that does not end before it starts"));
+        }
+        if *range.end() >= DynBitIndex::PARSE_CAPACITY {
+            return Err(Unexpected::new(to.trim(), format!("a value below {}", DynBitIndex::PARSE_CAPACITY)));
+        }
+        Ok(range)
+    }
+
+    let (name, ranges) = s.split_once(": ")
+        .ok_or_else(|| Unexpected::new(s, "a field like `<name>: 1-3 or 5-7`"))?;
+    Ok(Field {
+        name: name.into(),
+        ranges: ranges.split(" or ").map(range).collect::<Result<_, _>>()?,
+    })
+}
+
+fn parse_ticket(s: &str) -> Result<Vec<usize>, Unexpected<'_>> {
+    s.split(',').map(number).collect()
+}
+
+/// The fields, your ticket and the nearby tickets, in sections with a blank line between them.
+fn parse(input: &str) -> Result<Notes, Unexpected<'_>> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let expect = |i: usize, header: &str| {
+        let expected = if header.is_empty() { "a blank line".to_string() } else { format!("{:?}", header) };
+        match lines.get(i).copied() {
+            Some(line) if line == header => Ok(()),
+            Some(line) => Err(Unexpected::new(line, expected)),
+            None => Err(Unexpected::end_of(input, expected)),
+        }
+    };
+
+    let blank = lines.iter().position(|l| l.is_empty()).unwrap_or(lines.len());
+    let fields = lines[..blank].iter()
+        .copied()
+        .map(parse_field)
+        .collect::<Result<_, _>>()?;
+
+    expect(blank + 1, "your ticket:")?;
+    let ticket = parse_ticket(lines.get(blank + 2).copied().ok_or_else(|| Unexpected::end_of(input, "your ticket"))?)?;
+    expect(blank + 3, "")?;
+    expect(blank + 4, "nearby tickets:")?;
+    let nearby_tickets = lines.iter()
+        .copied()
+        .skip(blank + 5)
+        .map(|line| match parse_ticket(line)? {
+            values if values.len() == ticket.len() => Ok(values),
+            _ => Err(Unexpected::new(line, format!("{} values, as on your ticket", ticket.len()))),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Notes {
+        fields,
+        ticket,
+        nearby_tickets,
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Ticket Translation";
+
+    type Input = Notes;
+    type Output = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(notes: &Notes) -> Answer<usize> {
+        Answer::Found(a::error_rate(notes))
+    }
+
+    fn part2(notes: &Notes) -> Answer<usize> {
+        b::departure_product(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+
+your ticket:
+7,1
+
+nearby tickets:
+7,3
+40,4
+";
+
+    #[test]
+    fn test_parse() {
+        let notes = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Field { name: "row".into(), ranges: vec![6..=11, 33..=44] }, notes.fields[1]);
+        assert_eq!(vec![7, 1], notes.ticket);
+        assert_eq!(vec![vec![7, 3], vec![40, 4]], notes.nearby_tickets);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day16::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 6: expected a range like 1-3, found \"6\"", error(&EXAMPLE.replace("6-11", "6")));
+        assert_eq!("line 4, column 1: expected \"your ticket:\", found \"my ticket:\"", error(&EXAMPLE.replace("your", "my")));
+        assert_eq!("line 9, column 1: expected a number, found \"x\"", error(&EXAMPLE.replace("40", "x")));
+        assert_eq!("line 2, column 1: expected \"your ticket:\", found nothing", error("class: 1-3 or 5-7\n"));
+        assert_eq!("line 1, column 15: expected a range that does not end before it starts, found \"7-5\"", error(&EXAMPLE.replace("5-7", "7-5")));
+        assert_eq!("line 1, column 17: expected a value below 16777216, found \"18446744073709551615\"",
+                   error(&EXAMPLE.replace("5-7", "5-18446744073709551615")));
+        assert_eq!("line 8, column 1: expected 2 values, as on your ticket, found \"7\"", error(&EXAMPLE.replace("7,3", "7")));
+    }
+}
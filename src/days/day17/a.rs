@@ -0,0 +1,155 @@
+use self::State::{Inactive, Active};
+use std::fmt::{Debug, Formatter, Write};
+use std::convert::TryInto;
+
+use super::Slice;
+
+/// The number of active cubes after `cycles` cycles in three dimensions.
+pub fn active_after(slice: &Slice, cycles: usize) -> usize {
+    let o = (0..cycles).fold(PocketDimension::new(slice, cycles), |a, _| a.simulate());
+    o.active_cubes()
+}
+
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum State {
+    Inactive,
+    Active,
+}
+
+struct PocketDimension {
+    width: usize,
+    height: usize,
+    depth: usize,
+    height_stride: usize,
+    depth_stride: usize,
+    neighbours: [usize; 26],
+    cubes: Vec<State>,
+}
+
+impl PocketDimension {
+    fn new(slice: &Slice, max_cycles: usize) -> Self {
+        let input_width = slice.width();
+        let input_height = slice.height();
+
+        let max_growth = 2 * max_cycles;
+
+        let width = (input_width + 2) + max_growth;
+        let height = (input_height + 2) + max_growth;
+        let depth = (1 + 2) + max_growth;
+        let height_stride = width;
+        let depth_stride = height_stride * height;
+        let neighbours = (-1..=1)
+            .flat_map(|z| (-1..=1).map(move |y| (y, z)))
+            .flat_map(|(y, z)| (-1..=1).map(move |x| (x, y, z)))
+            .filter(|(x, y, z)| *x != 0 || *y != 0 || *z != 0)
+            .map(|(x, y, z)| x + (y * height_stride as isize) + (z * depth_stride as isize))
+            .map(|o| o as usize)
+            .collect::<Vec<_>>();
+        let neighbours: [usize; 26] = neighbours.try_into().unwrap();
+
+        let mut cubes = vec![Inactive; depth_stride * depth];
+        let x0 = (width - input_width) / 2;
+        let y0 = (height - input_height) / 2;
+        let z0 = depth / 2;
+
+
+        for v in 0..input_height {
+            for u in 0..input_width {
+                let state = if slice[(u, v)] { Active } else { Inactive };
+                cubes[(x0 + u) + (y0 + v) * height_stride + (z0 * depth_stride)] = state;
+            }
+        }
+
+        Self {
+            width,
+            height,
+            depth,
+            depth_stride,
+            height_stride,
+            neighbours,
+            cubes
+        }
+    }
+
+    fn active_neighbors(&self, o: usize) -> usize {
+        self.neighbours.iter()
+            .filter(|n| self.cubes[o.wrapping_add(**n)] == Active)
+            .count()
+    }
+
+    fn simulate(&self) -> Self {
+        let mut cubes = vec![Inactive; self.depth_stride * self.depth];
+
+        for z in 1..self.depth - 1 {
+            let zo = z * self.depth_stride;
+            for y in 1..self.height - 1 {
+                let yo = y * self.height_stride;
+                for x in 1..self.width - 1 {
+                    let o = x + yo + zo;
+                    let next_state = match (self.cubes[o], self.active_neighbors(o)) {
+                        (Active, 2) | (Active, 3) => Active,
+                        (Inactive, 3) => Active,
+                        _ => Inactive,
+                    };
+                    cubes[o] = next_state
+                }
+            }
+        }
+
+        Self {
+            cubes,
+            .. *self
+        }
+
+    }
+
+    fn active_cubes(&self) -> usize {
+        self.cubes.iter().filter(|s| **s == Active).count()
+    }
+}
+
+impl Debug for PocketDimension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for z in 0..self.depth {
+            write!(f, "\nz = {}", z)?;
+            let zo = z * self.depth_stride;
+            for y in 0..self.height {
+                f.write_char('\n')?;
+                let yo = y * self.height_stride;
+                for x in 0..self.width {
+                    let o = x + yo + zo;
+                    match self.cubes[o] {
+                        Inactive => f.write_char('.')?,
+                        Active => f.write_char('#')?
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod benches {
+    extern crate test;
+
+    use std::io::Read;
+    use test::bench::Bencher;
+
+    use crate::days::day17::Day17;
+    use crate::solution::Solution;
+
+    use super::*;
+
+    #[bench]
+    fn benchmark(bencher: &mut Bencher) {
+        let mut f = std::fs::File::open("src/bin/aoc17.txt").unwrap();
+        let mut input = String::new();
+        f.read_to_string(&mut input).unwrap();
+        let slice = Day17::parse(&input).unwrap();
+
+        bencher.iter(move || (0..6)
+            .fold(PocketDimension::new(&slice, 6), |a, _| a.simulate()));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::grid::Grid;
+use crate::solution::{ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+/// The initial two dimensional slice of cubes, `true` where a cube is active.
+pub type Slice = Grid<bool>;
+
+fn parse(input: &str) -> Result<Slice, Unexpected<'_>> {
+    let slice = Grid::parse(input, "'.' or '#'", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+    if slice.cells().next().is_none() {
+        return Err(Unexpected::end_of(input, "at least one cube"));
+    }
+    Ok(slice)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    type Input = Slice;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|e| ParseError::new(Self::DAY, input, e))
+    }
+
+    fn part1(slice: &Slice) -> usize {
+        a::active_after(slice, 6)
+    }
+
+    fn part2(slice: &Slice) -> usize {
+        b::active_after(slice, 6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day17::parse(input).unwrap_err().to_string();
+        assert_eq!("line 2, column 1: expected a row of 3 tiles, found \".#\"", error(".#.\n.#\n"));
+        assert_eq!("line 1, column 2: expected '.' or '#', found \"o\"", error(".o.\n"));
+        assert_eq!("line 1, column 1: expected at least one cube, found nothing", error(""));
+    }
+}
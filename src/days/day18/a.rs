@@ -0,0 +1,41 @@
+use super::{eval, Token};
+
+/// Addition and multiplication bind equally tightly.
+fn precedence(token: Token) -> usize {
+    match token {
+        Token::Plus => 1,
+        Token::Mul => 1,
+        _ => 0,
+    }
+}
+
+pub fn sum(expressions: &[Vec<Token>]) -> i64 {
+    expressions.iter()
+        .map(|e| eval(e, precedence))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day18::{as_postfix, tokens};
+
+    use super::*;
+
+    #[test]
+    fn test_to_postfix() {
+        assert_eq!(vec![Token::Literal(1)], as_postfix([Token::Literal(1)].iter().copied(), precedence).collect::<Vec<_>>());
+        assert_eq!(vec![Token::Literal(1), Token::Literal(20), Token::Plus], as_postfix([Token::Literal(1), Token::Plus, Token::Literal(20)].iter().copied(), precedence).collect::<Vec<_>>());
+        assert_eq!(vec![Token::Literal(1), Token::Literal(20), Token::Plus, Token::Literal(30), Token::Mul],
+                   as_postfix([Token::Literal(1), Token::Plus, Token::Literal(20), Token::Mul, Token::Literal(30)].iter().copied(), precedence).collect::<Vec<_>>());
+        assert_eq!(vec![Token::Literal(1), Token::Literal(20), Token::Literal(30), Token::Mul, Token::Plus],
+                   as_postfix([Token::Literal(1), Token::Plus, Token::LParen, Token::Literal(20), Token::Mul, Token::Literal(30), Token::RParen].iter().copied(), precedence).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(1i64, eval(&tokens("1").unwrap(), precedence));
+        assert_eq!(2i64, eval(&tokens("1+1").unwrap(), precedence));
+        assert_eq!(4i64, eval(&tokens("1+1*2").unwrap(), precedence));
+        assert_eq!(3i64, eval(&tokens("1+(1*2)").unwrap(), precedence));
+    }
+}
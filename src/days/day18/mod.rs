@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use crate::solution::{parse_lines, ParseError, Solution, Unexpected};
+
+pub mod a;
+pub mod b;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Token {
+    Literal(i64),
+    Plus,
+    Mul,
+    LParen,
+    RParen,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Literal(n) => write!(f, "{}", n),
+            Token::Plus => f.write_str("'+'"),
+            Token::Mul => f.write_str("'*'"),
+            Token::LParen => f.write_str("'('"),
+            Token::RParen => f.write_str("')'"),
+        }
+    }
+}
+
+/// Splits an expression into tokens, checking that operators and parentheses are where they belong.
+fn tokens(s: &str) -> Result<Vec<Token>, Unexpected<'_>> {
+    let mut tokens = Vec::new();
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let token = match bytes[i] {
+            b' ' => {
+                i += 1;
+                continue;
+            }
+            b'(' => Token::LParen,
+            b')' => Token::RParen,
+            b'+' => Token::Plus,
+            b'*' => Token::Mul,
+            b if b.is_ascii_digit() => {
+                while i + 1 < bytes.len() && bytes[i + 1].is_ascii_digit() {
+                    i += 1;
+                }
+                Token::Literal(s[start..=i].parse().map_err(|_| Unexpected::new(&s[start..=i], "a smaller number"))?)
+            }
+            _ => {
+                let c = s[i..].chars().next().unwrap();
+                return Err(Unexpected::new(&s[i..i + c.len_utf8()], "a number, an operator or a parenthesis"));
+            }
+        };
+        i += 1;
+        tokens.push((token, &s[start..i]));
+    }
+
+    let mut depth = 0;
+    let mut operand = true;
+    for (token, text) in &tokens {
+        match (token, operand) {
+            (Token::Literal(_), true) => operand = false,
+            (Token::LParen, true) => depth += 1,
+            (Token::RParen, false) if depth > 0 => depth -= 1,
+            (Token::Plus | Token::Mul, false) => operand = true,
+            (_, true) => return Err(Unexpected::new(text, "a number or '('")),
+            (_, false) => return Err(Unexpected::new(text, "an operator or ')'")),
+        }
+    }
+    match (operand, depth) {
+        (true, _) => Err(Unexpected::end_of(s, "a number or '('")),
+        (false, 0) => Ok(tokens.into_iter().map(|(token, _)| token).collect()),
+        (false, _) => Err(Unexpected::end_of(s, "')'")),
+    }
+}
+
+fn as_postfix(input: impl Iterator<Item=Token>, precedence: fn(Token) -> usize) -> impl Iterator<Item=Token> {
+    let mut stack: VecDeque<Token> = VecDeque::new();
+    let mut input = input.peekable();
+    std::iter::from_fn(move || {
+        while let Some(token) = input.peek() {
+            match token {
+                Token::Literal(_) => return Some(input.next().unwrap()),
+                Token::Plus | Token::Mul => {
+                    if let Some(front) = stack.front() {
+                        if precedence(*front) >= precedence(*token) {
+                            return stack.pop_front();
+                        }
+                    }
+                    stack.push_front(input.next().unwrap())
+                }
+                Token::LParen => stack.push_front(input.next().unwrap()),
+                Token::RParen => {
+                    if let Some(t) = stack.pop_front() {
+                        if t == Token::LParen {
+                            input.next().unwrap();
+                        } else {
+                            return Some(t);
+                        }
+                    }
+                }
+            }
+        }
+        stack.pop_front()
+    })
+}
+
+/// Evaluates an expression, binding the operator of higher `precedence` first.
+fn eval(expression: &[Token], precedence: fn(Token) -> usize) -> i64 {
+    fn apply(stack: &mut VecDeque<i64>, token: Token) {
+        match token {
+            Token::Literal(n) => stack.push_front(n),
+            Token::Plus => {
+                let lhs = stack.pop_front().unwrap();
+                let rhs = stack.pop_front().unwrap();
+                stack.push_front(lhs + rhs)
+            }
+            Token::Mul => {
+                let lhs = stack.pop_front().unwrap();
+                let rhs = stack.pop_front().unwrap();
+                stack.push_front(lhs * rhs)
+            }
+            _ => unreachable!()
+        }
+    }
+    as_postfix(expression.iter().copied(), precedence)
+        .fold(VecDeque::new(), |mut stack, token| {
+            apply(&mut stack, token);
+            stack
+        }).pop_front().unwrap()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Operation Order";
+
+    type Input = Vec<Vec<Token>>;
+    type Output = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let expressions = parse_lines(input, |line| if line.is_empty() {
+            Ok(None)
+        } else {
+            tokens(line).map(Some)
+        }).map_err(|e| ParseError::new(Self::DAY, input, e))?;
+        Ok(expressions.into_iter().flatten().collect())
+    }
+
+    fn part1(expressions: &Vec<Vec<Token>>) -> i64 {
+        a::sum(expressions)
+    }
+
+    fn part2(expressions: &Vec<Vec<Token>>) -> i64 {
+        b::sum(expressions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(vec![Token::Literal(1)], tokens("1").unwrap());
+        assert_eq!(vec![Token::Literal(1), Token::Plus, Token::Literal(20)], tokens("1 + 20").unwrap());
+        assert_eq!(vec![Token::Literal(1), Token::Mul, Token::Literal(20)], tokens("1 * 20").unwrap());
+        assert_eq!(vec![Token::LParen, Token::Literal(20), Token::RParen], tokens("(20)").unwrap());
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        let error = |input: &str| Day18::parse(input).unwrap_err().to_string();
+        assert_eq!("line 1, column 3: expected a number, an operator or a parenthesis, found \"-\"", error("1 - 2"));
+        assert_eq!("line 1, column 5: expected a number or '(', found \"*\"", error("1 + * 2"));
+        assert_eq!("line 1, column 3: expected an operator or ')', found \"(\"", error("1 (2)"));
+        assert_eq!("line 1, column 4: expected an operator or ')', found \")\"", error("(1))"));
+        assert_eq!("line 1, column 5: expected ')', found nothing", error("((1)"));
+        assert_eq!("line 2, column 4: expected a number or '(', found nothing", error("1 + 2\n3 *\n"));
+        assert_eq!("line 1, column 1: expected a smaller number, found \"99999999999999999999\"", error("99999999999999999999"));
+    }
+}
@@ -0,0 +1,206 @@
+//! The solutions for each day, and a registry to look them up by day and part.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::solution::{self, ParseError, Solution};
+use crate::timing::Timing;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Part::A => "a",
+            Part::B => "b",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsePartError(String);
+
+impl Display for ParsePartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid part {:?}, expected a or b", self.0)
+    }
+}
+
+impl Error for ParsePartError {}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(ParsePartError(s.to_string())),
+        }
+    }
+}
+
+/// One way of solving one part of one day.
+pub struct Solver {
+    pub day: u32,
+    pub title: &'static str,
+    pub part: Part,
+    /// Distinguishes alternative implementations of the same part, e.g. `unsafe`.
+    pub variant: Option<&'static str>,
+    solve: fn(&str) -> Result<String, ParseError>,
+    time: fn(&str, usize) -> Result<Timing, ParseError>,
+}
+
+impl Solver {
+    /// The day, part and variant, e.g. `14b` or `14b_unsafe`.
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{:02}{}_{}", self.day, self.part, variant),
+            None => format!("{:02}{}", self.day, self.part),
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> Result<String, ParseError> {
+        (self.solve)(input)
+    }
+
+    /// Parses and solves `input` `runs` times, timing each step.
+    pub fn time(&self, input: &str, runs: usize) -> Result<Timing, ParseError> {
+        (self.time)(input, runs)
+    }
+}
+
+macro_rules! solver {
+    ($solution:ty, $part:ident) => {
+        solver!($solution, $part, None)
+    };
+    ($solution:ty, $part:ident, $variant:expr) => {
+        Solver {
+            day: <$solution>::DAY,
+            title: <$solution>::TITLE,
+            part: Part::$part,
+            variant: $variant,
+            solve: |input| solution::run::<$solution>(input, Part::$part),
+            time: |input, runs| solution::time::<$solution>(input, Part::$part, runs),
+        }
+    };
+}
+
+static SOLVERS: &[Solver] = &[
+    solver!(day01::Day01, A),
+    solver!(day01::Day01, B),
+    solver!(day02::Day02, A),
+    solver!(day02::Day02, B),
+    solver!(day03::Day03, A),
+    solver!(day03::Day03, B),
+    solver!(day04::Day04, A),
+    solver!(day04::Day04, B),
+    solver!(day05::Day05, A),
+    solver!(day05::Day05, B),
+    solver!(day06::Day06, A),
+    solver!(day06::Day06, B),
+    solver!(day07::Day07, A),
+    solver!(day07::Day07, B),
+    solver!(day08::Day08, A),
+    solver!(day08::Day08, B),
+    solver!(day09::Day09, A),
+    solver!(day09::Day09, B),
+    solver!(day10::Day10, A),
+    solver!(day10::Day10, B),
+    solver!(day11::Day11, A),
+    solver!(day11::Day11, B),
+    solver!(day12::Day12, A),
+    solver!(day12::Day12, B),
+    solver!(day13::Day13, A),
+    solver!(day13::Day13, B),
+    solver!(day14::Day14, A),
+    solver!(day14::Day14, B),
+    #[cfg(target_arch = "x86_64")]
+    solver!(day14::Day14Unsafe, B, Some("unsafe")),
+    solver!(day15::Day15, A),
+    solver!(day15::Day15, B),
+    solver!(day16::Day16, A),
+    solver!(day16::Day16, B),
+    solver!(day17::Day17, A),
+    solver!(day17::Day17, B),
+    solver!(day18::Day18, A),
+    solver!(day18::Day18, B),
+];
+
+/// Every solver, ordered by day, part and then variant.
+pub fn solvers() -> &'static [Solver] {
+    SOLVERS
+}
+
+/// The solvers for `day`, optionally of one `part`, of `variant` (`None` for the main implementation).
+pub fn find(day: u32, part: Option<Part>, variant: Option<&str>) -> impl Iterator<Item=&'static Solver> + '_ {
+    SOLVERS.iter()
+        .filter(move |s| s.day == day)
+        .filter(move |s| part.is_none_or(|p| s.part == p))
+        .filter(move |s| s.variant == variant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered() {
+        let keys = solvers().iter()
+            .map(|s| (s.day, s.part, s.variant))
+            .collect::<Vec<_>>();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+        assert_eq!((1..=18).collect::<Vec<_>>(), {
+            let mut days = keys.iter().map(|k| k.0).collect::<Vec<_>>();
+            days.dedup();
+            days
+        });
+    }
+
+    #[test]
+    fn test_find() {
+        let names = |day, part, variant| find(day, part, variant).map(Solver::name).collect::<Vec<_>>();
+        assert_eq!(vec!["14a", "14b"], names(14, None, None));
+        assert_eq!(vec!["14b"], names(14, Some(Part::B), None));
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(vec!["14b_unsafe"], names(14, Some(Part::B), Some("unsafe")));
+        assert!(names(14, Some(Part::A), Some("unsafe")).is_empty());
+        assert!(names(19, None, None).is_empty());
+    }
+
+    #[test]
+    fn test_solve() {
+        let solver = find(1, Some(Part::A), None).next().unwrap();
+        assert_eq!(Ok("514579".to_string()), solver.solve("1721\n979\n366\n299\n675\n1456\n"));
+        assert_eq!("line 2, column 1: expected a number, found \"x\"", solver.solve("1721\nx\n").unwrap_err().to_string());
+        assert_eq!("01a", solver.name());
+        assert_eq!("514579", solver.time("1721\n979\n366\n299\n675\n1456\n", 2).unwrap().answer);
+    }
+}
@@ -0,0 +1,636 @@
+//! Random, well-formed puzzle input for each day, for stress testing and benchmarking at scale.
+//!
+//! The same day, size and seed always give the same input. What the size counts depends on the
+//! day, see [`default_size`]; it is clamped to what the puzzle can hold where that is limited.
+//! Inputs are built so that both parts have an answer, and where the puzzle promises a unique
+//! answer, so does the generated input.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// A small, fast pseudo random number generator (SplitMix64). Not for anything but test data.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `from..=to`.
+    pub fn between(&mut self, from: usize, to: usize) -> usize {
+        from + self.below(to - from + 1)
+    }
+
+    /// True `percent` percent of the time.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// What the size of each day's input counts, and a size like the real puzzle input.
+pub fn default_size(day: u32) -> Option<usize> {
+    Some(match day {
+        1 => 200, // numbers
+        2 => 1000, // password lines
+        3 => 323, // rows of 31 squares, at most 20000
+        4 => 250, // passports
+        5 => 800, // boarding passes, at most 1000
+        6 => 450, // groups
+        7 => 600, // bag colors, at most 900
+        8 => 600, // instructions
+        9 => 1000, // numbers, at most 1000
+        10 => 100, // adapters
+        11 => 90, // rows and columns of the seat layout
+        12 => 780, // navigation instructions
+        13 => 60, // schedule entries
+        14 => 570, // instructions
+        15 => 6, // starting numbers
+        16 => 240, // nearby tickets, for 20 fields
+        17 => 8, // rows and columns of the slice
+        18 => 370, // expressions
+        _ => return None,
+    })
+}
+
+/// Input for `day` of the given size, or `None` if there is no such day.
+pub fn generate(day: u32, size: usize, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed ^ ((day as u64) << 56));
+    let rng = &mut rng;
+    Some(match day {
+        1 => expense_report(rng, size),
+        2 => passwords(rng, size),
+        3 => tree_map(rng, size),
+        4 => passports(rng, size),
+        5 => boarding_passes(rng, size),
+        6 => questionnaires(rng, size),
+        7 => bag_rules(rng, size),
+        8 => handheld_program(rng, size),
+        9 => xmas_numbers(rng, size),
+        10 => adapters(rng, size),
+        11 => seat_layout(rng, size),
+        12 => navigation(rng, size),
+        13 => bus_notes(rng, size),
+        14 => bitmask_program(rng, size),
+        15 => starting_numbers(rng, size),
+        16 => ticket_notes(rng, size),
+        17 => cube_slice(rng, size),
+        18 => expressions(rng, size),
+        _ => return None,
+    })
+}
+
+fn lines<I: IntoIterator<Item=T>, T: std::fmt::Display>(items: I) -> String {
+    items.into_iter().fold(String::new(), |mut s, item| {
+        writeln!(s, "{}", item).unwrap();
+        s
+    })
+}
+
+/// Exactly one pair and one triple summing to 2020: the pair and triple each have one number
+/// below 1010, and every other number is above it and completes no other sum.
+fn expense_report(rng: &mut Rng, size: usize) -> String {
+    let (a, b) = (rng.between(1, 500), rng.between(1, 500));
+    let x = loop {
+        let x = rng.between(1, 1009);
+        if x != a && x != b && x != a + b {
+            break x;
+        }
+    };
+    let small = [x, a, b];
+    let forbidden = small.iter()
+        .flat_map(|s| small.iter().map(move |t| (s, t)))
+        .filter(|(s, t)| s != t)
+        .map(|(s, t)| 2020 - s - t)
+        .chain(small.iter().map(|s| 2020 - s))
+        .collect::<BTreeSet<_>>();
+    let mut numbers = vec![x, 2020 - x, a, b, 2020 - a - b];
+    while numbers.len() < size {
+        let n = rng.between(1011, 2019);
+        if !forbidden.contains(&n) {
+            numbers.push(n);
+        }
+    }
+    rng.shuffle(&mut numbers);
+    lines(numbers)
+}
+
+fn passwords(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let first = rng.between(1, 6);
+        let second = rng.between(first + 1, first + 10);
+        let c = (b'a' + rng.below(26) as u8) as char;
+        let password = (0..rng.between(1, 20))
+            .map(|_| if rng.chance(30) { c } else { (b'a' + rng.below(26) as u8) as char })
+            .collect::<String>();
+        format!("{}-{} {}: {}", first, second, c, password)
+    }))
+}
+
+/// At most 20000 rows, so that the product of the trees on each slope fits 64 bits.
+fn tree_map(rng: &mut Rng, size: usize) -> String {
+    lines((0..size.clamp(1, 20_000)).map(|_| (0..31)
+        .map(|_| if rng.chance(25) { '#' } else { '.' })
+        .collect::<String>()))
+}
+
+fn passport_field(rng: &mut Rng, key: &str, valid: bool) -> String {
+    let value = match (key, valid) {
+        ("byr", true) => rng.between(1920, 2002).to_string(),
+        ("iyr", true) => rng.between(2010, 2020).to_string(),
+        ("eyr", true) => rng.between(2020, 2030).to_string(),
+        ("byr", false) | ("iyr", false) | ("eyr", false) => rng.between(1900, 2040).to_string(),
+        ("hgt", true) if rng.chance(50) => format!("{}cm", rng.between(150, 193)),
+        ("hgt", true) => format!("{}in", rng.between(59, 76)),
+        ("hgt", false) => format!("{}{}", rng.between(50, 200), rng.choose(&["", "cm", "in"])),
+        ("hcl", true) => format!("#{:06x}", rng.below(1 << 24)),
+        ("hcl", false) => format!("{:06x}", rng.below(1 << 24)),
+        ("ecl", true) => rng.choose(&["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]).to_string(),
+        ("ecl", false) => rng.choose(&["zzz", "red", "gmt", "xry"]).to_string(),
+        ("pid", true) => format!("{:09}", rng.below(1_000_000_000)),
+        ("pid", false) => format!("{}", rng.below(1_000_000_000_000)),
+        _ => rng.between(1, 350).to_string(),
+    };
+    format!("{}:{}", key, value)
+}
+
+fn passports(rng: &mut Rng, size: usize) -> String {
+    let keys = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+    let passports = (0..size).map(|_| {
+        let mut fields = Vec::new();
+        for key in keys.iter() {
+            let present = if *key == "cid" { rng.chance(50) } else { !rng.chance(3) };
+            if present {
+                let valid = !rng.chance(5);
+                fields.push(passport_field(rng, key, valid));
+            }
+        }
+        if fields.is_empty() {
+            fields.push(passport_field(rng, "cid", true));
+        }
+        rng.shuffle(&mut fields);
+        let mut passport = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            let separator = if i == 0 { "" } else if rng.chance(25) { "\n" } else { " " };
+            passport.push_str(separator);
+            passport.push_str(field);
+        }
+        passport
+    });
+    passports.collect::<Vec<_>>().join("\n\n") + "\n"
+}
+
+/// A contiguous run of seat ids with one missing, whose rows either side are full.
+fn boarding_passes(rng: &mut Rng, size: usize) -> String {
+    let len = size.clamp(32, 1000) + 1;
+    let start = rng.between(8, 1016 - len);
+    let full_rows = start.div_ceil(8) + 1..(start + len) / 8 - 1;
+    let missing = rng.between(full_rows.start, full_rows.end - 1) * 8 + rng.below(8);
+    let mut ids = (start..start + len).filter(|&id| id != missing).collect::<Vec<_>>();
+    rng.shuffle(&mut ids);
+    lines(ids.into_iter().map(|id| (0..10)
+        .map(|bit| match (bit < 7, id >> (9 - bit) & 1) {
+            (true, 0) => 'F',
+            (true, _) => 'B',
+            (false, 0) => 'L',
+            (false, _) => 'R',
+        })
+        .collect::<String>()))
+}
+
+fn questionnaires(rng: &mut Rng, size: usize) -> String {
+    let groups = (0..size).map(|_| {
+        let people = (0..rng.between(1, 5)).map(|_| {
+            let mut answers = (b'a'..=b'z').filter(|_| rng.chance(40)).map(char::from).collect::<Vec<_>>();
+            if answers.is_empty() {
+                answers.push(char::from(b'a' + rng.below(26) as u8));
+            }
+            rng.shuffle(&mut answers);
+            answers.into_iter().collect::<String>()
+        });
+        people.collect::<Vec<_>>().join("\n")
+    });
+    groups.collect::<Vec<_>>().join("\n\n") + "\n"
+}
+
+const ADJECTIVES: [&str; 30] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "hazy", "dusty",
+    "mellow", "bold", "vivid", "deep", "soft", "matte", "glossy", "rough", "smooth", "warm",
+];
+
+const COLORS: [&str; 30] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan",
+    "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive",
+    "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "white",
+];
+
+/// An acyclic graph of rules. Each bag only contains bags a little later in the list, and is emptied if it
+/// would hold more than a trillion bags, so the count of bags in shiny gold fits any integer.
+fn bag_rules(rng: &mut Rng, size: usize) -> String {
+    const MAX_CONTAINED: usize = 1_000_000_000_000;
+    let mut names = ADJECTIVES.iter()
+        .flat_map(|a| COLORS.iter().map(move |c| format!("{} {}", a, c)))
+        .filter(|name| name != "shiny gold")
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(2, names.len()) - 1);
+    let gold = rng.between(1, names.len() / 2 + 1).min(names.len());
+    names.insert(gold, "shiny gold".to_string());
+
+    let mut contains = vec![Vec::new(); names.len()];
+    let mut totals = vec![0; names.len()];
+    for i in (0..names.len()).rev() {
+        let later = names.len() - i - 1;
+        let mut inner = (0..rng.below(4).min(later))
+            .map(|_| (rng.between(1, 5), i + 1 + rng.below(later.min(40))))
+            .collect::<Vec<_>>();
+        inner.sort_by_key(|&(_, j)| j);
+        inner.dedup_by_key(|&mut (_, j)| j);
+        let total = inner.iter().map(|&(n, j)| n.saturating_mul(1 + totals[j])).fold(0usize, usize::saturating_add);
+        if total <= MAX_CONTAINED {
+            totals[i] = total;
+            contains[i] = inner;
+        }
+    }
+    if !contains[..gold].iter().flatten().any(|&(_, j)| j == gold) {
+        contains[rng.below(gold)].push((rng.between(1, 5), gold));
+    }
+
+    let mut rules = names.iter().zip(&contains)
+        .map(|(name, inner)| {
+            let inner = inner.iter()
+                .map(|&(n, j)| format!("{} {} bag{}", n, names[j], if n == 1 { "" } else { "s" }))
+                .collect::<Vec<_>>();
+            let inner = if inner.is_empty() { "no other bags".to_string() } else { inner.join(", ") };
+            format!("{} bags contain {}.", name, inner)
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+    lines(rules)
+}
+
+/// A program that would run off its end but for one `nop` made a `jmp` back, which every run
+/// passes: no `jmp` before it jumps past it, and every other `nop` is `nop +0`, so flipping
+/// anything else still loops.
+fn handheld_program(rng: &mut Rng, size: usize) -> String {
+    let len = size.max(2);
+    let corrupted = rng.between(1, len - 1);
+    let program = (0..len).map(|pc| {
+        if pc == corrupted {
+            return format!("jmp -{}", rng.between(1, pc));
+        }
+        let limit = if pc < corrupted { corrupted } else { len };
+        match rng.below(3) {
+            0 if pc + 1 < limit => format!("jmp +{}", rng.between(1, (limit - pc).min(10))),
+            1 => "nop +0".to_string(),
+            _ => {
+                let n = rng.between(1, 50) as i64;
+                format!("acc {:+}", if rng.chance(50) { n } else { -n })
+            }
+        }
+    });
+    lines(program)
+}
+
+/// Each number is the sum of the smallest and another of the 25 before it, until one that is
+/// instead the sum of a contiguous run of earlier numbers. The numbers roughly double every 25,
+/// which is why there can be at most 1000.
+fn xmas_numbers(rng: &mut Rng, size: usize) -> String {
+    const WINDOW: usize = 25;
+    let len = size.clamp(WINDOW + 10, 1000);
+    let mut numbers = BTreeSet::new();
+    while numbers.len() < WINDOW {
+        numbers.insert(rng.between(1, 75));
+    }
+    let mut numbers = numbers.into_iter().collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    let invalid_at = rng.between(WINDOW + 5, len - 1);
+    let is_sum = |window: &[usize], n: usize| window.iter()
+        .any(|&a| window.iter().any(|&b| a != b && a + b == n));
+    while numbers.len() < len {
+        let window = &numbers[numbers.len() - WINDOW..];
+        if numbers.len() == invalid_at {
+            let run = rng.between(2, 5);
+            let start = rng.below(invalid_at - WINDOW);
+            let n = numbers[start..start + run].iter().sum();
+            if !is_sum(window, n) && !numbers.contains(&n) {
+                numbers.push(n);
+            }
+            continue;
+        }
+        let smallest = *window.iter().min().unwrap();
+        let other = *rng.choose(window);
+        if other != smallest {
+            numbers.push(smallest + other);
+        }
+    }
+    lines(numbers)
+}
+
+/// Joltages one or three apart, using a step of three whenever a step of one would make the
+/// number of arrangements larger than 10¹⁵.
+fn adapters(rng: &mut Rng, size: usize) -> String {
+    const MAX_ARRANGEMENTS: usize = 1_000_000_000_000_000;
+    let mut joltages = vec![0usize];
+    let mut ways = vec![1usize];
+    let ways_to = |joltages: &[usize], ways: &[usize], j: usize| joltages.iter()
+        .zip(ways)
+        .rev()
+        .take_while(|(&k, _)| j - k <= 3)
+        .map(|(_, &w)| w)
+        .sum::<usize>();
+    while joltages.len() <= size.max(1) {
+        let last = *joltages.last().unwrap();
+        let step = if rng.chance(60) && ways_to(&joltages, &ways, last + 1) <= MAX_ARRANGEMENTS { 1 } else { 3 };
+        ways.push(ways_to(&joltages, &ways, last + step));
+        joltages.push(last + step);
+    }
+    let mut adapters = joltages.split_off(1);
+    rng.shuffle(&mut adapters);
+    lines(adapters)
+}
+
+/// A layout whose seating settles under the rules of both parts. With much less than a quarter
+/// floor, random layouts tend to end up with a block of seats that fills and empties forever, and
+/// the few that still do are drawn again.
+fn seat_layout(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let layout = (0..size)
+            .map(|_| (0..size).map(|_| !rng.chance(25)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        if settles(&layout, false) && settles(&layout, true) {
+            return lines(layout.iter().map(|row| row.iter()
+                .map(|&seat| if seat { 'L' } else { '.' })
+                .collect::<String>()));
+        }
+    }
+}
+
+/// Whether seating in `layout` stops changing within a few rounds per seat row, by the rules of
+/// the first part or, with `sight`, the second.
+fn settles(layout: &[Vec<bool>], sight: bool) -> bool {
+    let (height, width) = (layout.len() as isize, layout[0].len() as isize);
+    let seats = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|&(x, y)| layout[y as usize][x as usize])
+        .collect::<Vec<_>>();
+    let index = |x: isize, y: isize| seats.binary_search_by_key(&(y, x), |&(x, y)| (y, x)).ok();
+    let neighbours = seats.iter()
+        .map(|&(x, y)| (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&d| d != (0, 0))
+            .filter_map(|(dx, dy)| (1..)
+                .map(|n| (x + n * dx, y + n * dy))
+                .take_while(|&(x, y)| 0 <= x && x < width && 0 <= y && y < height)
+                .take(if sight { usize::MAX } else { 1 })
+                .find_map(|(x, y)| index(x, y)))
+            .collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let crowded = if sight { 5 } else { 4 };
+    let mut occupied = vec![false; seats.len()];
+    for _ in 0..4 * (width + height) {
+        let next = (0..seats.len())
+            .map(|i| match neighbours[i].iter().filter(|&&j| occupied[j]).count() {
+                0 => true,
+                n if n >= crowded => false,
+                _ => occupied[i],
+            })
+            .collect::<Vec<_>>();
+        if next == occupied {
+            return true;
+        }
+        occupied = next;
+    }
+    false
+}
+
+fn navigation(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| match rng.below(7) {
+        i @ 0..=3 => format!("{}{}", ["N", "S", "E", "W"][i], rng.between(1, 5)),
+        i @ 4..=5 => format!("{}{}", ["L", "R"][i - 4], 90 * rng.between(1, 3)),
+        _ => format!("F{}", rng.between(1, 100)),
+    }))
+}
+
+/// Distinct prime buses whose product stays below 10¹⁵, so the earliest timestamp fits.
+fn bus_notes(rng: &mut Rng, size: usize) -> String {
+    const MAX_PRODUCT: usize = 1_000_000_000_000_000;
+    let mut primes = (7..1000usize)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut primes);
+    let slots = size.max(1);
+    let mut schedule = vec!["x".to_string(); slots];
+    let mut product = 1;
+    for (slot, bus) in (0..slots).zip(primes) {
+        if slot == 0 || rng.chance(30) && product * bus < MAX_PRODUCT {
+            schedule[slot] = bus.to_string();
+            product *= bus;
+        }
+    }
+    format!("{}\n{}\n", rng.between(1_000_000, 1_010_000), schedule.join(","))
+}
+
+/// Masks with at most nine floating bits, each followed by a few writes.
+fn bitmask_program(rng: &mut Rng, size: usize) -> String {
+    let mut program = Vec::with_capacity(size);
+    while program.len() < size.max(1) {
+        let mut mask = (0..36).map(|_| if rng.chance(50) { '1' } else { '0' }).collect::<Vec<_>>();
+        for _ in 0..rng.between(1, 9) {
+            mask[rng.below(36)] = 'X';
+        }
+        program.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+        for _ in 0..rng.between(1, 6) {
+            program.push(format!("mem[{}] = {}", rng.below(1 << 16), rng.below(1 << 30)));
+        }
+    }
+    lines(program)
+}
+
+fn starting_numbers(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..size.max(1) * 3).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(size.max(1));
+    numbers.iter().map(usize::to_string).collect::<Vec<_>>().join(",") + "\n"
+}
+
+const TICKET_FIELDS: [&str; 20] = [
+    "departure location", "departure station", "departure platform", "departure track",
+    "departure date", "departure time", "arrival location", "arrival station", "arrival platform",
+    "arrival track", "class", "duration", "price", "route", "row", "seat", "train", "type", "wagon",
+    "zone",
+];
+
+/// Twenty fields with a staircase of ranges: the value `100 + 10k` is valid for fields `k` and
+/// up only, and appears in field `k`'s column, so only one mapping of fields to columns works.
+/// Values of 980 and up are valid for no field.
+fn ticket_notes(rng: &mut Rng, size: usize) -> String {
+    let n = TICKET_FIELDS.len();
+    let ranges = (0..n)
+        .map(|k| {
+            let first = (rng.between(1, 50), 105 + 10 * k);
+            let from = rng.between(300, 600);
+            (first, (from, rng.between(from + 50, 970)))
+        })
+        .collect::<Vec<_>>();
+    let mut columns = (0..n).collect::<Vec<_>>();
+    rng.shuffle(&mut columns);
+    let value = |rng: &mut Rng, k: usize| {
+        let ((a, b), (c, d)) = ranges[k];
+        if rng.chance(50) { rng.between(a, b) } else { rng.between(c, d) }
+    };
+    let ticket = |rng: &mut Rng, staircase: bool| columns.iter()
+        .map(|&k| if staircase { 100 + 10 * k } else { value(rng, k) })
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+
+    let mut notes = String::new();
+    let mut order = (0..n).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    for k in order {
+        let ((a, b), (c, d)) = ranges[k];
+        writeln!(notes, "{}: {}-{} or {}-{}", TICKET_FIELDS[k], a, b, c, d).unwrap();
+    }
+    writeln!(notes, "\nyour ticket:\n{}\n\nnearby tickets:", ticket(rng, false).join(",")).unwrap();
+    for i in 0..size.max(1) {
+        let mut values = ticket(rng, i == 0);
+        if i > 0 && rng.chance(25) {
+            values[rng.below(n)] = rng.between(980, 999).to_string();
+        }
+        writeln!(notes, "{}", values.join(",")).unwrap();
+    }
+    notes
+}
+
+fn cube_slice(rng: &mut Rng, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| (0..size.max(1))
+        .map(|_| if rng.chance(45) { '#' } else { '.' })
+        .collect::<String>()))
+}
+
+/// An expression of at least two and at most `budget` numbers, with parenthesised
+/// subexpressions, and how many numbers it has.
+fn expression(rng: &mut Rng, budget: usize, depth: usize) -> (String, usize) {
+    let terms = rng.between(2, 4).min(budget);
+    let mut s = String::new();
+    let mut used = 0;
+    for i in 0..terms {
+        if i > 0 {
+            s.push_str(if rng.chance(50) { " + " } else { " * " });
+        }
+        let available = budget - used - (terms - i - 1);
+        if depth < 2 && available >= 2 && rng.chance(30) {
+            let (inner, n) = expression(rng, available, depth + 1);
+            write!(s, "({})", inner).unwrap();
+            used += n;
+        } else {
+            write!(s, "{}", rng.between(1, 9)).unwrap();
+            used += 1;
+        }
+    }
+    (s, used)
+}
+
+/// Expressions of at most 12 single digit numbers, so each is below 9¹² whatever the precedence.
+fn expressions(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| expression(rng, 12, 0).0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, Part};
+    use crate::days::day16::{b::field_assignment, Day16};
+    use crate::solution::Solution;
+    use crate::Assignment;
+
+    #[test]
+    fn test_deterministic() {
+        for day in 1..=18 {
+            let size = default_size(day).unwrap();
+            assert_eq!(generate(day, size, 7), generate(day, size, 7), "day {}", day);
+            assert_ne!(generate(day, size, 7), generate(day, size, 8), "day {}", day);
+        }
+        assert_eq!(None, generate(19, 10, 0));
+    }
+
+    #[test]
+    fn test_solvable() {
+        let solvers = days::solvers().iter()
+            .filter(|s| !(s.day == 15 && s.part == Part::B));
+        for solver in solvers {
+            for seed in 0..4 {
+                let size = default_size(solver.day).unwrap() / 4 + 1;
+                let input = generate(solver.day, size, seed).unwrap();
+                match solver.solve(&input) {
+                    Ok(answer) => assert!(!answer.starts_with("no answer"), "{} with seed {}: {}\n{}", solver.name(), seed, answer, input),
+                    Err(e) => panic!("{} with seed {}: {}\n{}", solver.name(), seed, e, input),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seat_layout_settles() {
+        let block = vec![vec![true; 3]; 3];
+        assert!(settles(&block, false));
+        let blinking = [".LL.", "LLLL", "LLLL", ".LL."].iter()
+            .map(|row| row.chars().map(|c| c == 'L').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert!(!settles(&blinking, false));
+        assert!(settles(&blinking, true));
+        let layout = generate(11, 30, 1).unwrap()
+            .lines()
+            .map(|l| l.chars().map(|c| c == 'L').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert!(settles(&layout, false) && settles(&layout, true));
+    }
+
+    #[test]
+    fn test_unique_answers() {
+        for seed in 0..8 {
+            let report = generate(1, 50, seed).unwrap();
+            let numbers = report.lines().map(|l| l.parse::<usize>().unwrap()).collect::<Vec<_>>();
+            let pairs = (0..numbers.len())
+                .flat_map(|i| (i + 1..numbers.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| numbers[i] + numbers[j] == 2020)
+                .count();
+            assert_eq!(1, pairs, "{:?}", numbers);
+        }
+    }
+
+    #[test]
+    fn test_unique_field_mapping() {
+        for seed in 0..8 {
+            let input = generate(16, 40, seed).unwrap();
+            let notes = Day16::parse(&input).unwrap();
+            assert!(matches!(field_assignment(&notes), Assignment::Unique(_)), "seed {}:\n{}", seed, input);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! A rectangular grid of cells, parsed from and rendered as a character map.
+//!
+//! Cells are stored row by row and addressed `(x, y)` from the top left. Reading outside the grid
+//! is governed by its [`Edge`]: nothing is there, the grid repeats, or every cell beyond it is
+//! the same.
+
+use std::fmt::{Display, Formatter, Write};
+use std::ops::{Index, IndexMut};
+
+use crate::solution::Unexpected;
+
+/// The four orthogonal neighbours of a cell.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The eight neighbours of a cell, diagonals included.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+/// What is read outside the grid.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Edge<T> {
+    /// Nothing.
+    Bounded,
+    /// The grid repeats in every direction.
+    Wrapping,
+    /// This cell, everywhere.
+    Padded(T),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    edge: Edge<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `cells` in rows of `width`, bounded.
+    ///
+    /// # Panics
+    ///
+    /// If `cells` is not a whole number of rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(cells.len().is_multiple_of(width), "{} cells do not make rows of {}", cells.len(), width);
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Self { width, height, cells, edge: Edge::Bounded }
+    }
+
+    /// Parses a character map a row per line, mapping each character with `cell`, which returns
+    /// `None` for characters that are not `expected`.
+    pub fn parse<'a, F: FnMut(char) -> Option<T>>(s: &'a str, expected: &str, mut cell: F) -> Result<Self, Unexpected<'a>> {
+        let width = s.lines().next().map_or(0, |row| row.chars().count());
+        let mut cells = Vec::with_capacity(s.len());
+        for row in s.lines() {
+            let start = cells.len();
+            for (i, c) in row.char_indices() {
+                cells.push(cell(c).ok_or_else(|| Unexpected::new(&row[i..i + c.len_utf8()], expected))?);
+            }
+            if cells.len() - start != width {
+                return Err(Unexpected::new(row, format!("a row of {} tiles", width)));
+            }
+        }
+        Ok(Self::from_cells(width, cells))
+    }
+
+    pub fn with_edge(self, edge: Edge<T>) -> Self {
+        Self { edge, ..self }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn edge(&self) -> &Edge<T> {
+        &self.edge
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        0 <= x && (x as usize) < self.width && 0 <= y && (y as usize) < self.height
+    }
+
+    /// The cell at `(x, y)`, or what the edge has there if that is outside the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[y as usize * self.width + x as usize])
+        } else {
+            self.beyond(x, y)
+        }
+    }
+
+    #[cold]
+    fn beyond(&self, x: isize, y: isize) -> Option<&T> {
+        match &self.edge {
+            Edge::Bounded => None,
+            Edge::Wrapping if self.cells.is_empty() => None,
+            Edge::Wrapping => {
+                let (x, y) = (x.rem_euclid(self.width as isize), y.rem_euclid(self.height as isize));
+                Some(&self.cells[y as usize * self.width + x as usize])
+            }
+            Edge::Padded(cell) => Some(cell),
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell, row by row.
+    pub fn cells(&self) -> impl Iterator<Item=&T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        assert!(x < self.width, "column {} is out of range for a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The cells at `offsets` from `(x, y)` that the edge lets be read.
+    pub fn neighbours<'a>(&'a self, x: usize, y: usize, offsets: &'a [(isize, isize)]) -> impl Iterator<Item=&'a T> {
+        offsets.iter().filter_map(move |(dx, dy)| self.get(x as isize + dx, y as isize + dy))
+    }
+
+    /// The four orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item=&T> {
+        self.neighbours(x, y, &ORTHOGONAL)
+    }
+
+    /// The eight neighbours of `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item=&T> {
+        self.neighbours(x, y, &ADJACENT)
+    }
+
+    /// The cells seen looking from `(x, y)` in steps of `(dx, dy)`, nearest first, up to the
+    /// edge of the grid whatever lies beyond it.
+    pub fn ray(&self, x: usize, y: usize, (dx, dy): (isize, isize)) -> impl Iterator<Item=&T> {
+        let (x, y) = (x as isize, y as isize);
+        (1..)
+            .map(move |n| (x + n * dx, y + n * dy))
+            .take_while(move |&(x, y)| (dx, dy) != (0, 0) && self.contains(x, y))
+            .map(move |(x, y)| &self.cells[y as usize * self.width + x as usize])
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self {
+        Self::from_cells(width, vec![cell; width * height])
+    }
+
+    /// A grid of the same size and edge with each cell replaced by `f` of its position and value.
+    pub fn map<F: FnMut((usize, usize), &T) -> T>(&self, mut f: F) -> Self {
+        let width = self.width;
+        let cells = self.cells.iter().enumerate().map(|(i, cell)| f((i % width, i / width), cell)).collect();
+        Self { width: self.width, height: self.height, cells, edge: self.edge.clone() }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height,
+                "position ({}, {}) is out of range for a {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height,
+                "position ({}, {}) is out of range for a {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// The character map, a line per row, which [`Grid::parse`] reads back when the cells display
+/// as the characters they are parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..
+.#.
+..#
+##.
+";
+
+    fn grid() -> Grid<bool> {
+        Grid::parse(MAP, "'.' or '#'", |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }).unwrap()
+    }
+
+    fn digits(s: &str) -> Grid<u32> {
+        Grid::parse(s, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((3, 4), (grid.width(), grid.height()));
+        assert!(grid[(0, 0)] && grid[(1, 1)] && grid[(1, 3)]);
+        assert!(!grid[(2, 3)]);
+        assert_eq!(5, grid.cells().filter(|c| **c).count());
+
+        let error = |s| Grid::parse(s, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(Unexpected::new("x", "a digit"), error("12\n3x\n"));
+        assert_eq!(Unexpected::new("456", "a row of 2 tiles"), error("12\n456\n"));
+        assert_eq!((0, 0), { let g = digits(""); (g.width(), g.height()) });
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let grid = digits("123\n456\n");
+        assert_eq!("123\n456\n", grid.to_string());
+        assert_eq!(grid, digits(&grid.to_string()));
+        assert_eq!("", digits("").to_string());
+    }
+
+    #[test]
+    fn test_edges() {
+        let grid = digits("12\n34\n");
+        assert_eq!(None, grid.get(-1, 0));
+        assert_eq!(None, grid.get(0, 2));
+        let wrapping = grid.clone().with_edge(Edge::Wrapping);
+        assert_eq!(Some(&2), wrapping.get(-1, 0));
+        assert_eq!(Some(&3), wrapping.get(4, -3));
+        let padded = grid.with_edge(Edge::Padded(0));
+        assert_eq!(Some(&0), padded.get(2, 1));
+        assert_eq!(Some(&4), padded.get(1, 1));
+        assert_eq!(None, digits("").with_edge(Edge::Wrapping).get(0, 0));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789\n");
+        assert_eq!(vec![2, 4, 6, 8], grid.neighbours4(1, 1).copied().collect::<Vec<_>>());
+        assert_eq!(vec![1, 2, 3, 4, 6, 7, 8, 9], grid.neighbours8(1, 1).copied().collect::<Vec<_>>());
+        assert_eq!(vec![2, 4, 5], grid.neighbours8(0, 0).copied().collect::<Vec<_>>());
+        let padded = grid.clone().with_edge(Edge::Padded(0));
+        assert_eq!(vec![0, 0, 0, 0, 2, 0, 4, 5], padded.neighbours8(0, 0).copied().collect::<Vec<_>>());
+        let wrapping = grid.with_edge(Edge::Wrapping);
+        assert_eq!(vec![7, 3, 2, 4], wrapping.neighbours4(0, 0).copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits("1234\n5678\n9012\n").with_edge(Edge::Padded(0));
+        assert_eq!(vec![2, 3, 4], grid.ray(0, 0, (1, 0)).copied().collect::<Vec<_>>());
+        assert_eq!(vec![6, 1], grid.ray(0, 0, (1, 1)).copied().collect::<Vec<_>>());
+        assert_eq!(vec![7, 3], grid.ray(2, 2, (0, -1)).copied().collect::<Vec<_>>());
+        assert_eq!(Vec::<u32>::new(), grid.ray(3, 1, (1, 0)).copied().collect::<Vec<_>>());
+        assert_eq!(Vec::<u32>::new(), grid.ray(1, 1, (0, 0)).copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456\n");
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(vec![&[1, 2, 3][..], &[4, 5, 6][..]], grid.rows().collect::<Vec<_>>());
+        assert_eq!(vec![2, 5], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+                   grid.columns().map(|c| c.copied().collect::<Vec<_>>()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = digits("12\n34\n").with_edge(Edge::Padded(0));
+        let mapped = grid.map(|(x, y), n| n * 10 + (x + y) as u32);
+        assert_eq!(vec![10, 21, 31, 42], mapped.cells().copied().collect::<Vec<_>>());
+        assert_eq!(&Edge::Padded(0), mapped.edge());
+        let mut grid = Grid::new(2, 1, 0);
+        grid[(1, 0)] = 7;
+        assert_eq!("07\n", grid.to_string());
+    }
+}
@@ -0,0 +1,175 @@
+//! Locating and reading puzzle input.
+//!
+//! A day's input comes from, in order of preference: a path given on the command line (`-` for
+//! stdin), the file `aocNN.txt` in the directory named by `AOC_INPUT_DIR`, or the input checked in
+//! next to the sources.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+fn file_name(day: u32) -> String {
+    format!("aoc{:02}.txt", day)
+}
+
+impl Source {
+    /// A command line argument: `-` for stdin, anything else a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(arg.into())
+        }
+    }
+
+    /// The input checked in with the sources.
+    pub fn checked_in(day: u32) -> Self {
+        Source::Path(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/bin").join(file_name(day)))
+    }
+
+    /// The day's file in `AOC_INPUT_DIR` if that is set, otherwise the checked in input.
+    pub fn default_for(day: u32) -> Self {
+        match std::env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => Source::Path(Path::new(&dir).join(file_name(day))),
+            None => Self::checked_in(day),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let mut text = String::new();
+        let result = match self {
+            Source::Path(path) => std::fs::File::open(path).and_then(|mut f| f.read_to_string(&mut text)),
+            Source::Stdin => std::io::stdin().read_to_string(&mut text),
+        };
+        result
+            .map(|_| text)
+            .map_err(|error| InputError { source: self.clone(), error })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => f.write_str("<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: std::io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not read input from {}: {}", self.source, self.error)?;
+        if self.error.kind() == std::io::ErrorKind::NotFound {
+            write!(f, " (pass a path or `-` for stdin, or set {} to a directory of aocNN.txt files)", INPUT_DIR_VAR)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Puzzle input and where it was read from.
+pub struct Input {
+    pub source: Source,
+    pub text: String,
+    day: u32,
+}
+
+impl Input {
+    pub fn read(day: u32, source: Source) -> Result<Self, InputError> {
+        let text = source.read()?;
+        Ok(Self { source, text, day })
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Whether this is the input checked in with the sources.
+    pub fn is_checked_in(&self) -> bool {
+        match (&self.source, Source::checked_in(self.day)) {
+            (Source::Path(path), Source::Path(checked_in)) => path == &checked_in
+                || matches!((path.canonicalize(), checked_in.canonicalize()), (Ok(a), Ok(b)) if a == b),
+            _ => false,
+        }
+    }
+
+    /// The name of the input in the answers manifest: `checked-in`, `stdin`, or the file stem.
+    pub fn name(&self) -> String {
+        match &self.source {
+            _ if self.is_checked_in() => "checked-in".to_string(),
+            Source::Path(path) => path.file_stem().map_or(String::new(), |s| s.to_string_lossy().into_owned()),
+            Source::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+/// The input for `day` of a per-day binary, from its first argument or the default location.
+///
+/// Prints the error and exits if the input can not be read.
+pub fn from_args(day: u32) -> Input {
+    from_arg(day, std::env::args().nth(1).as_deref())
+}
+
+/// The input for `day` from a command line argument, or the default location if there is none.
+///
+/// Prints the error and exits if the input can not be read.
+pub fn from_arg(day: u32, arg: Option<&str>) -> Input {
+    let source = match arg {
+        Some(arg) => Source::from_arg(arg),
+        None => Source::default_for(day),
+    };
+    Input::read(day, source).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::Stdin, Source::from_arg("-"));
+        assert_eq!(Source::Path("inputs/aoc07.txt".into()), Source::from_arg("inputs/aoc07.txt"));
+    }
+
+    #[test]
+    fn test_checked_in() {
+        let input = Input::read(7, Source::checked_in(7)).unwrap();
+        assert!(input.is_checked_in());
+        assert_eq!("checked-in", input.name());
+        let relative = Input::read(7, Source::from_arg("src/bin/aoc07.txt")).unwrap();
+        assert!(relative.is_checked_in());
+        assert!(input.text.contains("shiny gold"));
+    }
+
+    #[test]
+    fn test_missing_file_is_named() {
+        let e = Input::read(7, Source::from_arg("no/such/aoc07.txt")).err().unwrap();
+        let message = e.to_string();
+        assert!(message.starts_with("could not read input from no/such/aoc07.txt: "), "{}", message);
+        assert!(message.contains(INPUT_DIR_VAR), "{}", message);
+    }
+}
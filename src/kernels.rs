@@ -0,0 +1,224 @@
+//! Bulk word kernels behind the bit index operators.
+//!
+//! Words are processed `LANES` at a time through fixed-size arrays with independent
+//! accumulators, which lets the compiler keep them in wide registers and vectorize the loop on
+//! stable Rust. The trailing `len % LANES` words take the scalar path.
+
+use std::convert::TryInto;
+
+const LANES: usize = 4;
+
+#[inline]
+pub fn assign<F>(lhs: &mut [u128], rhs: &[u128], op: F)
+    where
+        F: Fn(u128, u128) -> u128 {
+    let len = lhs.len().min(rhs.len());
+    let (lhs, rhs) = (&mut lhs[..len], &rhs[..len]);
+    let mut l_chunks = lhs.chunks_exact_mut(LANES);
+    let mut r_chunks = rhs.chunks_exact(LANES);
+    for (l, r) in (&mut l_chunks).zip(&mut r_chunks) {
+        let l: &mut [u128; LANES] = l.try_into().unwrap();
+        let r: &[u128; LANES] = r.try_into().unwrap();
+        for i in 0..LANES {
+            l[i] = op(l[i], r[i]);
+        }
+    }
+    l_chunks.into_remainder()
+        .iter_mut()
+        .zip(r_chunks.remainder())
+        .for_each(|(l, r)| *l = op(*l, *r));
+}
+
+#[inline]
+pub fn count_ones(words: &[u128]) -> usize {
+    let chunks = words.chunks_exact(LANES);
+    let tail = chunks.remainder()
+        .iter()
+        .map(|v| v.count_ones() as usize)
+        .sum::<usize>();
+    let mut acc = [0usize; LANES];
+    for w in chunks {
+        let w: &[u128; LANES] = w.try_into().unwrap();
+        for i in 0..LANES {
+            acc[i] += w[i].count_ones() as usize;
+        }
+    }
+    acc.iter().sum::<usize>() + tail
+}
+
+#[inline]
+pub fn is_zero(words: &[u128]) -> bool {
+    let chunks = words.chunks_exact(LANES);
+    if chunks.remainder().iter().any(|v| *v != 0) {
+        return false;
+    }
+    chunks.into_iter()
+        .all(|w| w.iter().fold(0u128, |a, v| a | v) == 0)
+}
+
+#[inline]
+pub fn intersection_count(a: &[u128], b: &[u128]) -> usize {
+    let len = a.len().min(b.len());
+    let a_chunks = a[..len].chunks_exact(LANES);
+    let b_chunks = b[..len].chunks_exact(LANES);
+    let tail = a_chunks.remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(l, r)| (l & r).count_ones() as usize)
+        .sum::<usize>();
+    let mut acc = [0usize; LANES];
+    for (l, r) in a_chunks.zip(b_chunks) {
+        let l: &[u128; LANES] = l.try_into().unwrap();
+        let r: &[u128; LANES] = r.try_into().unwrap();
+        for i in 0..LANES {
+            acc[i] += (l[i] & r[i]).count_ones() as usize;
+        }
+    }
+    acc.iter().sum::<usize>() + tail
+}
+
+/// The straightforward word-by-word kernels, kept as the reference for the batched ones.
+#[cfg(test)]
+mod scalar {
+    pub fn assign<F: Fn(u128, u128) -> u128>(lhs: &mut [u128], rhs: &[u128], op: F) {
+        lhs.iter_mut()
+            .zip(rhs.iter())
+            .for_each(move |(l, r)| *l = op(*l, *r));
+    }
+
+    pub fn count_ones(words: &[u128]) -> usize {
+        words.iter()
+            .map(|v| v.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_zero(words: &[u128]) -> bool {
+        words.iter().all(|v| *v == 0u128)
+    }
+
+    pub fn intersection_count(a: &[u128], b: &[u128]) -> usize {
+        a.iter()
+            .zip(b.iter())
+            .map(|(l, r)| (l & r).count_ones() as usize)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn word() -> impl Strategy<Value=u128> {
+        prop_oneof![
+            Just(0u128),
+            Just(!0u128),
+            any::<u128>(),
+            (0..128u32).prop_map(|b| 1u128 << b),
+        ]
+    }
+
+    fn words() -> impl Strategy<Value=Vec<u128>> {
+        vec(word(), 0..24)
+    }
+
+    proptest! {
+        #[test]
+        fn assign_matches_scalar(lhs in words(), rhs in words()) {
+            let ops: [fn(u128, u128) -> u128; 3] = [|l, r| l | r, |l, r| l & r, |l, r| l & !r];
+            for op in ops.iter() {
+                let mut batched = lhs.clone();
+                let mut reference = lhs.clone();
+                assign(&mut batched, &rhs, op);
+                scalar::assign(&mut reference, &rhs, op);
+                prop_assert_eq!(reference, batched);
+            }
+        }
+
+        #[test]
+        fn count_ones_matches_scalar(w in words()) {
+            prop_assert_eq!(scalar::count_ones(&w), count_ones(&w));
+        }
+
+        #[test]
+        fn is_zero_matches_scalar(w in words()) {
+            prop_assert_eq!(scalar::is_zero(&w), is_zero(&w));
+        }
+
+        #[test]
+        fn intersection_count_matches_scalar(a in words(), b in words()) {
+            prop_assert_eq!(scalar::intersection_count(&a, &b), intersection_count(&a, &b));
+        }
+    }
+
+    #[cfg(feature = "bench")]
+    mod benches {
+        extern crate test;
+
+        use test::bench::Bencher;
+
+        use super::*;
+
+        const BENCH_WORDS: usize = 4096;
+
+        fn bench_words(seed: u128) -> Vec<u128> {
+            (0..BENCH_WORDS as u128)
+                .map(|i| (i ^ seed).wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835))
+                .collect()
+        }
+
+        #[bench]
+        fn bench_assign_scalar(bencher: &mut Bencher) {
+            let mut lhs = bench_words(1);
+            let rhs = bench_words(2);
+            bencher.iter(|| scalar::assign(&mut lhs, &rhs, |l, r| l & !r));
+        }
+
+        #[bench]
+        fn bench_assign_batched(bencher: &mut Bencher) {
+            let mut lhs = bench_words(1);
+            let rhs = bench_words(2);
+            bencher.iter(|| assign(&mut lhs, &rhs, |l, r| l & !r));
+        }
+
+        #[bench]
+        fn bench_count_ones_scalar(bencher: &mut Bencher) {
+            let w = bench_words(1);
+            bencher.iter(|| scalar::count_ones(&w));
+        }
+
+        #[bench]
+        fn bench_count_ones_batched(bencher: &mut Bencher) {
+            let w = bench_words(1);
+            bencher.iter(|| count_ones(&w));
+        }
+
+        #[bench]
+        fn bench_is_zero_scalar(bencher: &mut Bencher) {
+            let w = vec![0u128; BENCH_WORDS];
+            bencher.iter(|| scalar::is_zero(&w));
+        }
+
+        #[bench]
+        fn bench_is_zero_batched(bencher: &mut Bencher) {
+            let w = vec![0u128; BENCH_WORDS];
+            bencher.iter(|| is_zero(&w));
+        }
+
+        #[bench]
+        fn bench_intersection_count_scalar(bencher: &mut Bencher) {
+            let a = bench_words(1);
+            let b = bench_words(2);
+            bencher.iter(|| scalar::intersection_count(&a, &b));
+        }
+
+        #[bench]
+        fn bench_intersection_count_batched(bencher: &mut Bencher) {
+            let a = bench_words(1);
+            let b = bench_words(2);
+            bencher.iter(|| intersection_count(&a, &b));
+        }
+    }
+}
@@ -1,28 +1,75 @@
-#![feature(const_generics)]
+#![cfg_attr(all(test, feature = "bench"), feature(test))]
 
-use std::fmt::{Debug, Formatter};
-use std::ops::{RangeBounds, AddAssign, BitOrAssign, BitAndAssign, SubAssign};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::{RangeBounds, RangeInclusive, AddAssign, BitOrAssign, BitAndAssign, SubAssign, BitOr, BitAnd, BitXor, Sub, Not};
 use std::ops::Bound::{Included, Excluded, Unbounded};
+use std::str::FromStr;
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+pub use matrix::{Assignment, BitMatrix, Column, Row};
+
+pub mod answers;
+pub mod days;
+pub mod generate;
+pub mod grid;
+pub mod input;
+mod kernels;
+mod matrix;
+pub mod parallel;
+pub mod solution;
+pub mod timing;
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct BitIndex<const N: usize>([u128; N]);
 
-fn from_bound<T: RangeBounds<usize>>(range: &T) -> usize {
+fn from_bound<T: RangeBounds<usize>>(range: &T) -> Option<usize> {
     match range.start_bound() {
-        Included(i) => *i,
-        Excluded(i) => i + 1,
-        Unbounded => 0,
+        Included(i) => Some(*i),
+        Excluded(i) => i.checked_add(1),
+        Unbounded => Some(0),
     }
 }
 
-fn to_bound<const N: usize, T: RangeBounds<usize>>(range: &T) -> usize {
+fn to_bound<T: RangeBounds<usize>, F: FnOnce() -> usize>(range: &T, unbounded: F) -> Option<usize> {
     match range.end_bound() {
-        Included(i) => *i,
-        Excluded(i) => i - 1,
-        Unbounded => N * 128 - 1
+        Included(i) => Some(*i),
+        Excluded(i) => i.checked_sub(1),
+        Unbounded => Some(unbounded()),
+    }
+}
+
+/// The inclusive `(from, to)` span covered by `range`, or `None` if the range is empty.
+fn span<T: RangeBounds<usize>, F: FnOnce() -> usize>(range: &T, unbounded: F) -> Option<(usize, usize)> {
+    let from = from_bound(range)?;
+    let to = to_bound(range, unbounded)?;
+    if from <= to {
+        Some((from, to))
+    } else {
+        None
+    }
+}
+
+/// A value that does not fit in a fixed capacity [`BitIndex`], or beyond what a parsed
+/// [`DynBitIndex`] may grow to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CapacityError {
+    pub value: usize,
+    pub capacity: usize,
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value {} is out of range for a bit index with capacity {}", self.value, self.capacity)
     }
 }
 
+impl Error for CapacityError {}
+
 #[inline]
 fn pos(v: usize) -> (usize, u128) {
     let i = v >> 7;
@@ -31,127 +78,668 @@ fn pos(v: usize) -> (usize, u128) {
 }
 
 #[inline]
-fn assign_op<const N: usize, F>(lhs: &mut [u128; N], rhs: &[u128; N], op: F)
-    where
-        F: Fn(u128, u128) -> u128 {
-    lhs.iter_mut()
-        .zip(rhs.iter())
-        .for_each(move |(l, r)| *l = op(*l, *r));
+fn words(bits: usize) -> usize {
+    (bits + 127) >> 7
+}
+
+fn fill_range(a: &mut [u128], from: usize, to: usize) {
+    let (from_i, from_bit) = pos(from);
+    let (to_i, to_bit) = pos(to);
+    let from_mask = !(from_bit - 1);
+    let to_mask = (to_bit - 1) | to_bit;
+    if from_i == to_i {
+        a[from_i] |= from_mask & to_mask
+    } else {
+        a[from_i] |= from_mask;
+        a[to_i] |= to_mask;
+        a[from_i + 1..to_i].iter_mut().for_each(|w| *w = !0u128);
+    }
+}
+
+/// Position of a double-ended walk over the members of a bit set's words.
+#[derive(Clone)]
+struct Cursor {
+    front: usize,
+    front_offset: usize,
+    front_bits: u128,
+    back: usize,
+    back_offset: usize,
+    back_bits: u128,
+    remaining: usize,
 }
 
+impl Cursor {
+    fn new(words: &[u128]) -> Self {
+        Self {
+            front: 0,
+            front_offset: 0,
+            front_bits: 0,
+            back: words.len(),
+            back_offset: 0,
+            back_bits: 0,
+            remaining: kernels::count_ones(words),
+        }
+    }
+
+    fn next(&mut self, words: &[u128]) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.front_bits == 0 {
+            if self.front < self.back {
+                self.front_offset = self.front << 7;
+                self.front_bits = words[self.front];
+                self.front += 1;
+            } else {
+                self.front_offset = self.back_offset;
+                self.front_bits = std::mem::take(&mut self.back_bits);
+            }
+        }
+        self.remaining -= 1;
+        let lsb = self.front_bits.trailing_zeros();
+        self.front_bits ^= 1 << lsb;
+        Some(self.front_offset + lsb as usize)
+    }
+
+    fn next_back(&mut self, words: &[u128]) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        while self.back_bits == 0 {
+            if self.front < self.back {
+                self.back -= 1;
+                self.back_offset = self.back << 7;
+                self.back_bits = words[self.back];
+            } else {
+                self.back_offset = self.front_offset;
+                self.back_bits = std::mem::take(&mut self.front_bits);
+            }
+        }
+        self.remaining -= 1;
+        let msb = 127 - self.back_bits.leading_zeros();
+        self.back_bits ^= 1 << msb;
+        Some(self.back_offset + msb as usize)
+    }
+}
+
+/// Iterator over the members of a bit set, in ascending order.
+#[derive(Clone)]
+pub struct Bits<'a> {
+    words: &'a [u128],
+    cursor: Cursor,
+}
+
+impl<'a> Bits<'a> {
+    fn new(words: &'a [u128]) -> Self {
+        Self {
+            words,
+            cursor: Cursor::new(words),
+        }
+    }
+}
+
+impl Iterator for Bits<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next(self.words)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.remaining, Some(self.cursor.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Bits<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor.next_back(self.words)
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+impl FusedIterator for Bits<'_> {}
+
+/// Owning iterator over the members of a bit set, in ascending order.
+#[derive(Clone)]
+pub struct IntoBits<S> {
+    words: S,
+    cursor: Cursor,
+}
+
+impl<S: AsRef<[u128]>> IntoBits<S> {
+    fn new(words: S) -> Self {
+        let cursor = Cursor::new(words.as_ref());
+        Self { words, cursor }
+    }
+}
+
+impl<S: AsRef<[u128]>> Iterator for IntoBits<S> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next(self.words.as_ref())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.remaining, Some(self.cursor.remaining))
+    }
+}
+
+impl<S: AsRef<[u128]>> DoubleEndedIterator for IntoBits<S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor.next_back(self.words.as_ref())
+    }
+}
+
+impl<S: AsRef<[u128]>> ExactSizeIterator for IntoBits<S> {}
+
+impl<S: AsRef<[u128]>> FusedIterator for IntoBits<S> {}
+
+/// Orders bit sets by comparing their ascending member sequences lexicographically, treating
+/// missing words as empty.
+fn cmp_words(a: &[u128], b: &[u128]) -> Ordering {
+    let word = |w: &[u128], i: usize| w.get(i).copied().unwrap_or(0);
+    let len = a.len().max(b.len());
+    let i = match (0..len).find(|i| word(a, *i) != word(b, *i)) {
+        Some(i) => i,
+        None => return Ordering::Equal,
+    };
+    let (wa, wb) = (word(a, i), word(b, i));
+    let p = (wa ^ wb).trailing_zeros();
+    let above = !((1u128 << p) | ((1u128 << p) - 1));
+    // The set holding `p` is smaller, unless the other set has run out of members
+    let more = |w: &[u128], head: u128| head & above != 0 || w.iter().skip(i + 1).any(|v| *v != 0);
+    if wa & (1 << p) != 0 {
+        if more(b, wb) { Ordering::Less } else { Ordering::Greater }
+    } else if more(a, wa) {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    }
+}
+
+/// Word-wise ordered navigation over bit set storage, shared by [`BitIndex`] and [`DynBitIndex`].
+mod select {
+    use super::pos;
+
+    #[inline]
+    fn offset(i: usize, bit: u32) -> usize {
+        (i << 7) + bit as usize
+    }
+
+    pub fn first(words: &[u128]) -> Option<usize> {
+        words.iter()
+            .enumerate()
+            .find(|(_, w)| **w != 0)
+            .map(|(i, w)| offset(i, w.trailing_zeros()))
+    }
+
+    pub fn last(words: &[u128]) -> Option<usize> {
+        words.iter()
+            .enumerate()
+            .rev()
+            .find(|(_, w)| **w != 0)
+            .map(|(i, w)| offset(i, 127 - w.leading_zeros()))
+    }
+
+    pub fn nth(words: &[u128], mut n: usize) -> Option<usize> {
+        for (i, w) in words.iter().enumerate() {
+            let count = w.count_ones() as usize;
+            if n < count {
+                let mut w = *w;
+                for _ in 0..n {
+                    w &= w - 1;
+                }
+                return Some(offset(i, w.trailing_zeros()));
+            }
+            n -= count;
+        }
+        None
+    }
+
+    pub fn rank(words: &[u128], v: usize) -> usize {
+        let (i, bit) = pos(v);
+        let below = words.iter()
+            .take(i)
+            .map(|w| w.count_ones() as usize)
+            .sum::<usize>();
+        below + words.get(i).map_or(0, |w| (w & (bit - 1)).count_ones() as usize)
+    }
+
+    pub fn next_after(words: &[u128], v: usize) -> Option<usize> {
+        let (i, bit) = pos(v);
+        let head = words.get(i)
+            .map(|w| w & !((bit - 1) | bit))
+            .filter(|w| *w != 0)
+            .map(|w| offset(i, w.trailing_zeros()));
+        head.or_else(|| words.get(i + 1..).and_then(first).map(|v| v + ((i + 1) << 7)))
+    }
+
+    pub fn prev_before(words: &[u128], v: usize) -> Option<usize> {
+        let (i, bit) = pos(v);
+        if i >= words.len() {
+            return last(words);
+        }
+        let w = words[i] & (bit - 1);
+        if w != 0 {
+            Some(offset(i, 127 - w.leading_zeros()))
+        } else {
+            last(&words[..i])
+        }
+    }
+
+    pub fn single(words: &[u128]) -> Option<usize> {
+        let (i, w) = words.iter()
+            .enumerate()
+            .find(|(_, w)| **w != 0)?;
+        if w.count_ones() == 1 && words[i + 1..].iter().all(|w| *w == 0) {
+            Some(offset(i, w.trailing_zeros()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over the maximal runs of consecutive members of a bit set, in ascending order.
+pub struct Ranges<'a> {
+    words: &'a [u128],
+    next: usize,
+}
+
+impl<'a> Ranges<'a> {
+    fn new(words: &'a [u128]) -> Self {
+        Self { words, next: 0 }
+    }
+
+    fn next_set(&self, v: usize) -> Option<usize> {
+        let (i, bit) = pos(v);
+        let w = self.words.get(i)? & !(bit - 1);
+        if w != 0 {
+            return Some((i << 7) + w.trailing_zeros() as usize);
+        }
+        self.words[i + 1..].iter()
+            .position(|w| *w != 0)
+            .map(|j| ((i + 1 + j) << 7) + self.words[i + 1 + j].trailing_zeros() as usize)
+    }
+
+    fn next_clear(&self, v: usize) -> usize {
+        let (i, bit) = pos(v);
+        let w = self.words.get(i).map_or(!0u128, |w| !w) & !(bit - 1);
+        if w != 0 {
+            return (i << 7) + w.trailing_zeros() as usize;
+        }
+        self.words[i + 1..].iter()
+            .position(|w| *w != !0u128)
+            .map_or(self.words.len() << 7, |j| ((i + 1 + j) << 7) + self.words[i + 1 + j].trailing_ones() as usize)
+    }
+}
+
+impl Iterator for Ranges<'_> {
+    type Item = RangeInclusive<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next_set(self.next)?;
+        let end = self.next_clear(start);
+        self.next = end;
+        Some(start..=end - 1)
+    }
+}
+
+/// Writes the members of a bit set as runs, e.g. `{3..=7, 12, 40..=99}`.
+fn fmt_ranges(ranges: Ranges<'_>, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str("{")?;
+    for (i, range) in ranges.enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        if range.start() == range.end() {
+            write!(f, "{}", range.start())?;
+        } else {
+            write!(f, "{}..={}", range.start(), range.end())?;
+        }
+    }
+    f.write_str("}")
+}
+
+/// Parses the notation written by [`fmt_ranges`].
+fn parse_ranges(s: &str) -> Result<Vec<RangeInclusive<usize>>, ParseBitIndexError> {
+    let syntax = || ParseBitIndexError::Syntax(s.to_string());
+    let inner = s.trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(syntax)?
+        .trim();
+    if inner.is_empty() {
+        return Ok(Vec::new());
+    }
+    inner.split(',')
+        .map(|item| {
+            let item = item.trim();
+            let (from, to) = match item.split_once("..=") {
+                Some((from, to)) => (from, to),
+                None => (item, item),
+            };
+            match (from.trim().parse(), to.trim().parse()) {
+                (Ok(from), Ok(to)) if from <= to => Ok(from..=to),
+                _ => Err(ParseBitIndexError::Syntax(item.to_string())),
+            }
+        })
+        .collect()
+}
+
+/// A failure to parse a bit set from its `{3..=7, 12}` notation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseBitIndexError {
+    /// The text is not in set notation; holds the offending part.
+    Syntax(String),
+    Capacity(CapacityError),
+}
+
+impl Display for ParseBitIndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBitIndexError::Syntax(s) => write!(f, "invalid bit index notation: {:?}", s),
+            ParseBitIndexError::Capacity(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for ParseBitIndexError {}
+
+impl From<CapacityError> for ParseBitIndexError {
+    fn from(e: CapacityError) -> Self {
+        ParseBitIndexError::Capacity(e)
+    }
+}
+
+/// Operations shared by [`BitIndex`] and [`DynBitIndex`], so that code can be written once
+/// against either the fixed or the heap-backed representation.
+pub trait BitSet: Clone
+    + AddAssign<usize>
+    + SubAssign<usize>
+    + for<'a> BitOrAssign<&'a Self>
+    + for<'a> BitAndAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self> {
+    /// An empty set able to hold at least the values `0..bits`.
+    fn with_capacity(bits: usize) -> Self;
+
+    fn from_ranges<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I) -> Self;
+
+    fn contains(&self, v: usize) -> bool;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool;
+
+    fn iter(&self) -> Bits<'_>;
+
+    fn single(&self) -> Option<usize>;
+}
 
 impl<const N: usize> BitIndex<N> {
     pub fn is_empty(&self) -> bool {
-        self.0.iter().all(|v| *v == 0u128)
+        kernels::is_zero(&self.0)
     }
 
     pub fn empty() -> Self {
         Self([0u128; N])
     }
 
+    pub const CAPACITY: usize = N * 128;
+
+    fn check(v: usize) -> Result<(), CapacityError> {
+        if v < Self::CAPACITY {
+            Ok(())
+        } else {
+            Err(CapacityError { value: v, capacity: Self::CAPACITY })
+        }
+    }
+
+    /// Builds an index from the union of `ranges`. Empty ranges, such as `5..5` or `..0`,
+    /// contribute no members.
+    ///
+    /// # Panics
+    ///
+    /// If any non-empty range extends beyond the capacity. See [`BitIndex::try_from_ranges`].
     pub fn from_ranges<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I) -> Self {
+        Self::try_from_ranges(ranges).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like [`BitIndex::from_ranges`], but fails instead of panicking if any non-empty range
+    /// extends beyond the capacity.
+    pub fn try_from_ranges<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I) -> Result<Self, CapacityError> {
         let mut a = [0u128; N];
         for range in ranges {
-            let (from_i, from_bit) = pos(from_bound(&range));
-            let (to_i, to_bit) = pos(to_bound::<N, T>(&range));
-            let from_mask = !(from_bit - 1);
-            let to_mask = (to_bit - 1) | to_bit;
-            if from_i == to_i {
-                a[from_i] |= from_mask & to_mask
-            } else {
-                a[from_i] |= from_mask;
-                a[to_i] |= to_mask;
-                for i in from_i + 1..to_i {
-                    a[i] |= !0u128;
-                }
+            if let Some((from, to)) = span(&range, || Self::CAPACITY - 1) {
+                Self::check(to)?;
+                fill_range(&mut a, from, to);
             }
         }
-        Self(a)
+        Ok(Self(a))
     }
 
     pub fn from_range<R: RangeBounds<usize>>(range: R) -> Self {
-        let mut a = [0u128; N];
-        let (from_i, from_bit) = pos(from_bound(&range));
-        let (to_i, to_bit) = pos(to_bound::<N, _>(&range));
-        let from_mask = !(from_bit - 1);
-        let to_mask = (to_bit - 1) | to_bit;
-        if from_i == to_i {
-            a[from_i] |= from_mask & to_mask
-        } else {
-            a[from_i] |= from_mask;
-            a[to_i] |= to_mask;
-            for i in from_i + 1..to_i {
-                a[i] |= !0u128;
-            }
-        }
-
-        Self(a)
+        Self::from_ranges(std::iter::once(range))
     }
 
+    /// # Panics
+    ///
+    /// If `v` is beyond the capacity. See [`BitIndex::checked_contains`].
     #[inline]
     pub fn contains(&self, v: usize) -> bool {
+        self.checked_contains(v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    #[inline]
+    pub fn checked_contains(&self, v: usize) -> Result<bool, CapacityError> {
+        Self::check(v)?;
         let (i, bit) = pos(v);
-        self.0[i] & bit != 0
+        Ok(self.0[i] & bit != 0)
+    }
+
+    /// Adds `v`, returning whether it was not already a member.
+    #[inline]
+    pub fn try_insert(&mut self, v: usize) -> Result<bool, CapacityError> {
+        Self::check(v)?;
+        let (i, bit) = pos(v);
+        let absent = self.0[i] & bit == 0;
+        self.0[i] |= bit;
+        Ok(absent)
     }
 
     pub fn len(&self) -> usize {
+        kernels::count_ones(&self.0)
+    }
+
+    pub fn iter(&self) -> Bits<'_> {
+        Bits::new(&self.0)
+    }
+
+    /// The maximal runs of consecutive members, in ascending order.
+    pub fn ranges(&self) -> Ranges<'_> {
+        Ranges::new(&self.0)
+    }
+
+    /// The smallest member.
+    pub fn first(&self) -> Option<usize> {
+        select::first(&self.0)
+    }
+
+    /// The largest member.
+    pub fn last(&self) -> Option<usize> {
+        select::last(&self.0)
+    }
+
+    /// The `n`th smallest member, counting from zero.
+    pub fn nth_member(&self, n: usize) -> Option<usize> {
+        select::nth(&self.0, n)
+    }
+
+    /// The number of members strictly less than `v`.
+    pub fn rank(&self, v: usize) -> usize {
+        select::rank(&self.0, v)
+    }
+
+    /// The smallest member strictly greater than `v`.
+    pub fn next_after(&self, v: usize) -> Option<usize> {
+        select::next_after(&self.0, v)
+    }
+
+    /// The largest member strictly less than `v`.
+    pub fn prev_before(&self, v: usize) -> Option<usize> {
+        select::prev_before(&self.0, v)
+    }
+
+    /// The only member, if there is exactly one.
+    pub fn single(&self) -> Option<usize> {
+        select::single(&self.0)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
         self.0.iter()
-            .map(|v| v.count_ones() as usize)
-            .sum()
-    }
-
-    pub fn iter(&self) -> impl Iterator<Item=usize> + '_ {
-        fn b(offset: usize, mut bits: u128) -> impl Iterator<Item=usize> {
-            std::iter::from_fn(move || {
-                if bits != 0 {
-                    let lsb = bits.trailing_zeros();
-                    bits ^= 1 << lsb;
-                    Some(offset + lsb as usize)
-                } else {
-                    None
-                }
-            })
-        }
+            .zip(other.0.iter())
+            .all(|(l, r)| l & !r == 0)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
         self.0.iter()
-            .enumerate()
-            .filter(|(_, s)| **s != 0)
-            .flat_map(move |(i, s)| b(i << 7, *s))
+            .zip(other.0.iter())
+            .all(|(l, r)| l & r == 0)
+    }
+
+    /// The number of members in `self & other`, without constructing the intersection.
+    pub fn intersection_len(&self, other: &Self) -> usize {
+        kernels::intersection_count(&self.0, &other.0)
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self ^ other
+    }
+}
+
+impl<const N: usize> BitSet for BitIndex<N> {
+    fn with_capacity(bits: usize) -> Self {
+        assert!(bits <= N * 128, "BitIndex<{}> cannot hold {} bits", N, bits);
+        Self::empty()
+    }
+
+    fn from_ranges<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I) -> Self {
+        Self::from_ranges(ranges)
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        self.contains(v)
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn iter(&self) -> Bits<'_> {
+        self.iter()
+    }
+
+    fn single(&self) -> Option<usize> {
+        self.single()
+    }
+}
+
+impl<const N: usize> Default for BitIndex<N> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<const N: usize> Ord for BitIndex<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_words(&self.0, &other.0)
+    }
+}
+
+impl<const N: usize> PartialOrd for BitIndex<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for BitIndex<N> {
+    fn from_iter<I: IntoIterator<Item=usize>>(iter: I) -> Self {
+        let mut a = Self::empty();
+        a.extend(iter);
+        a
+    }
+}
+
+impl<const N: usize> Extend<usize> for BitIndex<N> {
+    fn extend<I: IntoIterator<Item=usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|v| *self += v);
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a BitIndex<N> {
+    type Item = usize;
+    type IntoIter = Bits<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> IntoIterator for BitIndex<N> {
+    type Item = usize;
+    type IntoIter = IntoBits<[u128; N]>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoBits::new(self.0)
     }
 }
 
 impl<const N: usize> AddAssign<usize> for BitIndex<N> {
+    /// # Panics
+    ///
+    /// If `rhs` is beyond the capacity. See [`BitIndex::try_insert`].
     #[inline]
     fn add_assign(&mut self, rhs: usize) {
-        let (i, bit) = pos(rhs);
-        self.0[i] |= bit;
+        if let Err(e) = self.try_insert(rhs) {
+            panic!("{}", e)
+        }
     }
 }
 
 impl<const N: usize> BitOrAssign<BitIndex<N>> for BitIndex<N> {
     #[inline]
     fn bitor_assign(&mut self, rhs: BitIndex<N>) {
-        assign_op(&mut self.0, &rhs.0, |l, r| l | r );
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l | r );
     }
 }
 
 impl<const N: usize> BitOrAssign<&BitIndex<N>> for BitIndex<N> {
     #[inline]
     fn bitor_assign(&mut self, rhs: &BitIndex<N>) {
-        assign_op(&mut self.0, &rhs.0, |l, r| l | r );
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l | r );
     }
 }
 
 impl<const N: usize> BitAndAssign<BitIndex<N>> for BitIndex<N> {
     #[inline]
     fn bitand_assign(&mut self, rhs: BitIndex<N>) {
-        assign_op(&mut self.0, &rhs.0, |l, r| l & r );
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l & r );
     }
 }
 
 impl<const N: usize> BitAndAssign<&BitIndex<N>> for BitIndex<N> {
     #[inline]
     fn bitand_assign(&mut self, rhs: &BitIndex<N>) {
-        assign_op(&mut self.0, &rhs.0, |l, r| l & r );
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l & r );
     }
 }
 
@@ -159,34 +747,754 @@ impl<const N: usize> BitAndAssign<&BitIndex<N>> for BitIndex<N> {
 impl<const N: usize> SubAssign<BitIndex<N>> for BitIndex<N> {
     #[inline]
     fn sub_assign(&mut self, rhs: BitIndex<N>) {
-        assign_op(&mut self.0, &rhs.0, |l, r| l & !r);
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l & !r);
     }
 }
 
 impl<const N: usize> SubAssign<&BitIndex<N>> for BitIndex<N> {
     #[inline]
     fn sub_assign(&mut self, rhs: &BitIndex<N>) {
-        assign_op(&mut self.0, &rhs.0, |l, r| l & !r);
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l & !r);
     }
 }
 
 impl<const N: usize> SubAssign<usize> for BitIndex<N> {
+    /// Removes `rhs`, which does nothing if it is beyond the capacity, as it cannot be present.
     #[inline]
     fn sub_assign(&mut self, rhs: usize) {
         let (i, bit) = pos(rhs);
-        self.0[i] &= !bit;
+        if let Some(w) = self.0.get_mut(i) {
+            *w &= !bit;
+        }
+    }
+}
+
+
+macro_rules! bit_index_op {
+    ($op:ident, $f:ident, $apply:expr) => {
+        impl<const N: usize> $op<BitIndex<N>> for BitIndex<N> {
+            type Output = BitIndex<N>;
+
+            #[inline]
+            fn $f(mut self, rhs: BitIndex<N>) -> BitIndex<N> {
+                kernels::assign(&mut self.0, &rhs.0, $apply);
+                self
+            }
+        }
+
+        impl<const N: usize> $op<&BitIndex<N>> for BitIndex<N> {
+            type Output = BitIndex<N>;
+
+            #[inline]
+            fn $f(mut self, rhs: &BitIndex<N>) -> BitIndex<N> {
+                kernels::assign(&mut self.0, &rhs.0, $apply);
+                self
+            }
+        }
+
+        impl<const N: usize> $op<BitIndex<N>> for &BitIndex<N> {
+            type Output = BitIndex<N>;
+
+            #[inline]
+            fn $f(self, rhs: BitIndex<N>) -> BitIndex<N> {
+                let mut a = *self;
+                kernels::assign(&mut a.0, &rhs.0, $apply);
+                a
+            }
+        }
+
+        impl<const N: usize> $op<&BitIndex<N>> for &BitIndex<N> {
+            type Output = BitIndex<N>;
+
+            #[inline]
+            fn $f(self, rhs: &BitIndex<N>) -> BitIndex<N> {
+                let mut a = *self;
+                kernels::assign(&mut a.0, &rhs.0, $apply);
+                a
+            }
+        }
+    };
+}
+
+bit_index_op!(BitOr, bitor, |l, r| l | r);
+bit_index_op!(BitAnd, bitand, |l, r| l & r);
+bit_index_op!(BitXor, bitxor, |l, r| l ^ r);
+bit_index_op!(Sub, sub, |l, r| l & !r);
+
+impl<const N: usize> Not for BitIndex<N> {
+    type Output = BitIndex<N>;
+
+    #[inline]
+    fn not(mut self) -> BitIndex<N> {
+        self.0.iter_mut().for_each(|v| *v = !*v);
+        self
     }
 }
 
+impl<const N: usize> Not for &BitIndex<N> {
+    type Output = BitIndex<N>;
+
+    #[inline]
+    fn not(self) -> BitIndex<N> {
+        !*self
+    }
+}
 
 impl<const N: usize> Debug for BitIndex<N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("[")?;
-        for (i, n) in self.0.iter().enumerate() {
-            if *n != 0 {
-                write!(f, " {:3} {:b}", i * 128, n)?
+        fmt_ranges(self.ranges(), f)
+    }
+}
+
+impl<const N: usize> Display for BitIndex<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_ranges(self.ranges(), f)
+    }
+}
+
+impl<const N: usize> FromStr for BitIndex<N> {
+    type Err = ParseBitIndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from_ranges(parse_ranges(s)?)?)
+    }
+}
+
+/// A heap-backed counterpart to [`BitIndex`] whose capacity is decided at runtime. It grows as
+/// values are inserted, and values beyond its capacity are simply not members.
+#[derive(Clone, Default)]
+pub struct DynBitIndex(Vec<u128>);
+
+impl DynBitIndex {
+    /// The capacity that parsing and deserialising grow an index to at most, so that a large
+    /// value in untrusted input is an error rather than an attempt at a huge allocation.
+    pub const PARSE_CAPACITY: usize = 1 << 24;
+
+    pub fn is_empty(&self) -> bool {
+        kernels::is_zero(&self.0)
+    }
+
+    pub fn empty() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self(vec![0u128; words(bits)])
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.len() << 7
+    }
+
+    fn reserve(&mut self, v: usize) {
+        let (i, _) = pos(v);
+        if i >= self.0.len() {
+            self.0.resize(i + 1, 0u128);
+        }
+    }
+
+    /// Builds an index from the union of `ranges`. Empty ranges contribute no members.
+    ///
+    /// # Panics
+    ///
+    /// If a range is unbounded above, as there is no capacity to extend it to.
+    pub fn from_ranges<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I) -> Self {
+        let mut a = Self::empty();
+        for range in ranges {
+            if let Some((from, to)) = span(&range, || panic!("DynBitIndex ranges must be bounded above")) {
+                a.reserve(to);
+                fill_range(&mut a.0, from, to);
+            }
+        }
+        a
+    }
+
+    /// Like [`DynBitIndex::from_ranges`], but fails instead of growing beyond `capacity`, or if a
+    /// non-empty range is unbounded above.
+    pub fn try_from_ranges_within<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I, capacity: usize) -> Result<Self, CapacityError> {
+        let mut a = Self::empty();
+        for range in ranges {
+            if let Some((from, to)) = span(&range, || usize::MAX) {
+                if to >= capacity {
+                    return Err(CapacityError { value: to, capacity });
+                }
+                a.reserve(to);
+                fill_range(&mut a.0, from, to);
             }
         }
-        f.write_str(" ]")
+        Ok(a)
+    }
+
+    pub fn from_range<R: RangeBounds<usize>>(range: R) -> Self {
+        Self::from_ranges(std::iter::once(range))
+    }
+
+    #[inline]
+    pub fn contains(&self, v: usize) -> bool {
+        let (i, bit) = pos(v);
+        self.0.get(i).is_some_and(|w| w & bit != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        kernels::count_ones(&self.0)
+    }
+
+    pub fn iter(&self) -> Bits<'_> {
+        Bits::new(&self.0)
+    }
+
+    /// The maximal runs of consecutive members, in ascending order.
+    pub fn ranges(&self) -> Ranges<'_> {
+        Ranges::new(&self.0)
+    }
+
+    /// The smallest member.
+    pub fn first(&self) -> Option<usize> {
+        select::first(&self.0)
+    }
+
+    /// The largest member.
+    pub fn last(&self) -> Option<usize> {
+        select::last(&self.0)
+    }
+
+    /// The `n`th smallest member, counting from zero.
+    pub fn nth_member(&self, n: usize) -> Option<usize> {
+        select::nth(&self.0, n)
+    }
+
+    /// The number of members strictly less than `v`.
+    pub fn rank(&self, v: usize) -> usize {
+        select::rank(&self.0, v)
+    }
+
+    /// The smallest member strictly greater than `v`.
+    pub fn next_after(&self, v: usize) -> Option<usize> {
+        select::next_after(&self.0, v)
+    }
+
+    /// The largest member strictly less than `v`.
+    pub fn prev_before(&self, v: usize) -> Option<usize> {
+        select::prev_before(&self.0, v)
+    }
+
+    /// The only member, if there is exactly one.
+    pub fn single(&self) -> Option<usize> {
+        select::single(&self.0)
+    }
+}
+
+impl BitSet for DynBitIndex {
+    fn with_capacity(bits: usize) -> Self {
+        Self::with_capacity(bits)
+    }
+
+    fn from_ranges<T: RangeBounds<usize>, I: IntoIterator<Item=T>>(ranges: I) -> Self {
+        Self::from_ranges(ranges)
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        self.contains(v)
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn iter(&self) -> Bits<'_> {
+        self.iter()
+    }
+
+    fn single(&self) -> Option<usize> {
+        self.single()
+    }
+}
+
+impl PartialEq for DynBitIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.significant_words() == other.significant_words()
+    }
+}
+
+impl Eq for DynBitIndex {}
+
+impl DynBitIndex {
+    /// The words up to and including the last non-zero one, so that equal sets compare and hash
+    /// alike regardless of capacity.
+    fn significant_words(&self) -> &[u128] {
+        let len = self.0.iter().rposition(|v| *v != 0).map_or(0, |i| i + 1);
+        &self.0[..len]
+    }
+}
+
+impl Hash for DynBitIndex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.significant_words().hash(state)
+    }
+}
+
+impl Ord for DynBitIndex {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_words(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for DynBitIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromIterator<usize> for DynBitIndex {
+    fn from_iter<I: IntoIterator<Item=usize>>(iter: I) -> Self {
+        let mut a = Self::empty();
+        a.extend(iter);
+        a
+    }
+}
+
+impl Extend<usize> for DynBitIndex {
+    fn extend<I: IntoIterator<Item=usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|v| *self += v);
+    }
+}
+
+impl<'a> IntoIterator for &'a DynBitIndex {
+    type Item = usize;
+    type IntoIter = Bits<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for DynBitIndex {
+    type Item = usize;
+    type IntoIter = IntoBits<Vec<u128>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoBits::new(self.0)
+    }
+}
+
+impl AddAssign<usize> for DynBitIndex {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: usize) {
+        self.reserve(rhs);
+        let (i, bit) = pos(rhs);
+        self.0[i] |= bit;
+    }
+}
+
+impl BitOrAssign<DynBitIndex> for DynBitIndex {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: DynBitIndex) {
+        *self |= &rhs;
+    }
+}
+
+impl BitOrAssign<&DynBitIndex> for DynBitIndex {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &DynBitIndex) {
+        if self.0.len() < rhs.0.len() {
+            self.0.resize(rhs.0.len(), 0u128);
+        }
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l | r );
+    }
+}
+
+impl BitAndAssign<DynBitIndex> for DynBitIndex {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: DynBitIndex) {
+        *self &= &rhs;
+    }
+}
+
+impl BitAndAssign<&DynBitIndex> for DynBitIndex {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &DynBitIndex) {
+        self.0.truncate(rhs.0.len());
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l & r );
+    }
+}
+
+impl SubAssign<DynBitIndex> for DynBitIndex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: DynBitIndex) {
+        *self -= &rhs;
+    }
+}
+
+impl SubAssign<&DynBitIndex> for DynBitIndex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &DynBitIndex) {
+        kernels::assign(&mut self.0, &rhs.0, |l, r| l & !r);
+    }
+}
+
+impl SubAssign<usize> for DynBitIndex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: usize) {
+        let (i, bit) = pos(rhs);
+        if let Some(w) = self.0.get_mut(i) {
+            *w &= !bit;
+        }
+    }
+}
+
+impl Debug for DynBitIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_ranges(self.ranges(), f)
+    }
+}
+
+impl Display for DynBitIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_ranges(self.ranges(), f)
+    }
+}
+
+impl FromStr for DynBitIndex {
+    type Err = ParseBitIndexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::try_from_ranges_within(parse_ranges(s)?, Self::PARSE_CAPACITY)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    type Index = BitIndex<2>;
+
+    fn index(values: &[usize]) -> Index {
+        values.iter().fold(Index::empty(), |mut a, v| { a += *v; a })
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_binary_ops() {
+        let a = index(&[1, 127, 128, 200]);
+        let b = index(&[127, 128, 129]);
+
+        assert_eq!(index(&[1, 127, 128, 129, 200]), a | b);
+        assert_eq!(index(&[127, 128]), a & b);
+        assert_eq!(index(&[1, 129, 200]), a ^ b);
+        assert_eq!(index(&[1, 200]), a - b);
+        assert_eq!(index(&[129]), b - a);
+
+        assert_eq!(a | b, &a | &b);
+        assert_eq!(a & b, a & &b);
+        assert_eq!(a ^ b, &a ^ b);
+        assert_eq!(a - b, &a - &b);
+    }
+
+    #[test]
+    fn test_not() {
+        let a = index(&[0, 127, 128, 255]);
+        let n = !a;
+        assert_eq!(252, n.len());
+        assert!(!n.contains(127));
+        assert!(!n.contains(128));
+        assert!(n.contains(126));
+        assert!(n.contains(129));
+        assert_eq!(a, !&n);
+        assert_eq!(Index::from_range(..), !Index::empty());
+    }
+
+    #[test]
+    fn test_subset_superset() {
+        let a = Index::from_range(120..=135);
+        let b = Index::from_range(127..=128);
+        assert!(b.is_subset(&a));
+        assert!(a.is_superset(&b));
+        assert!(!a.is_subset(&b));
+        assert!(a.is_subset(&a));
+        assert!(Index::empty().is_subset(&b));
+        assert!(!index(&[128]).is_subset(&index(&[127])));
+    }
+
+    #[test]
+    fn test_disjoint() {
+        let low = Index::from_range(..=127);
+        let high = Index::from_range(128..);
+        assert!(low.is_disjoint(&high));
+        assert!(!low.is_disjoint(&index(&[127])));
+        assert!(!high.is_disjoint(&index(&[128])));
+        assert!(Index::empty().is_disjoint(&Index::empty()));
+    }
+
+    #[test]
+    fn test_intersection_len() {
+        let a = Index::from_range(100..=150);
+        let b = Index::from_range(127..=200);
+        assert_eq!(24, a.intersection_len(&b));
+        assert_eq!((a & b).len(), a.intersection_len(&b));
+        assert_eq!(0, index(&[127]).intersection_len(&index(&[128])));
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let a = index(&[126, 127, 128]);
+        let b = index(&[127, 128, 129]);
+        assert_eq!(index(&[126, 129]), a.symmetric_difference(&b));
+        assert_eq!(a ^ b, b.symmetric_difference(&a));
+        assert!(a.symmetric_difference(&a).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_empty_ranges() {
+        assert!(Index::from_range(5..5).is_empty());
+        assert!(Index::from_range(..0).is_empty());
+        assert!(Index::from_range(7..=6).is_empty());
+        assert!(Index::from_ranges([300..300, 0..0]).is_empty());
+        assert_eq!(index(&[3, 4]), Index::from_ranges([3..5, 9..9]));
+        assert!(DynBitIndex::from_range(5..5).is_empty());
+        assert!(DynBitIndex::from_range(..0).is_empty());
+    }
+
+    #[test]
+    fn test_try_from_ranges() {
+        assert_eq!(Ok(Index::from_range(250..)), Index::try_from_ranges([250..=255]));
+        assert_eq!(Err(CapacityError { value: 256, capacity: 256 }), Index::try_from_ranges([0..=3, 250..=256]));
+        assert_eq!(Ok(Index::empty()), Index::try_from_ranges([300..300, 0..0]));
+    }
+
+    #[test]
+    fn test_checked_contains() {
+        let a = index(&[127, 255]);
+        assert_eq!(Ok(true), a.checked_contains(127));
+        assert_eq!(Ok(false), a.checked_contains(128));
+        assert_eq!(Ok(true), a.checked_contains(255));
+        assert_eq!(Err(CapacityError { value: 256, capacity: 256 }), a.checked_contains(256));
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut a = Index::empty();
+        assert_eq!(Ok(true), a.try_insert(128));
+        assert_eq!(Ok(false), a.try_insert(128));
+        assert_eq!(Err(CapacityError { value: 1000, capacity: 256 }), a.try_insert(1000));
+        assert_eq!(index(&[128]), a);
+    }
+
+    #[test]
+    #[should_panic(expected = "value 256 is out of range for a bit index with capacity 256")]
+    fn test_insert_out_of_range() {
+        let mut a = Index::empty();
+        a += 256;
+    }
+
+    #[test]
+    fn test_remove_out_of_range() {
+        let mut a = index(&[3, 255]);
+        a -= 255;
+        a -= 256;
+        a -= usize::MAX;
+        assert_eq!(index(&[3]), a);
+
+        let mut b = BitIndex::<1>::empty();
+        b -= 1000;
+        assert_eq!(BitIndex::<1>::empty(), b);
+    }
+
+    #[test]
+    fn test_first_last() {
+        assert_eq!(None, Index::empty().first());
+        assert_eq!(None, Index::empty().last());
+        let a = index(&[127, 128, 200]);
+        assert_eq!(Some(127), a.first());
+        assert_eq!(Some(200), a.last());
+        assert_eq!(Some(255), Index::from_range(..).last());
+        assert_eq!(Some(128), index(&[128]).first());
+        assert_eq!(Some(127), index(&[127]).last());
+    }
+
+    #[test]
+    fn test_nth_member() {
+        let a = index(&[3, 127, 128, 129, 255]);
+        let members = a.iter().collect::<Vec<_>>();
+        for (n, v) in members.iter().enumerate() {
+            assert_eq!(Some(*v), a.nth_member(n));
+        }
+        assert_eq!(None, a.nth_member(members.len()));
+        assert_eq!(Some(200), Index::from_range(..).nth_member(200));
+    }
+
+    #[test]
+    fn test_rank() {
+        let a = index(&[3, 127, 128, 129, 255]);
+        assert_eq!(0, a.rank(0));
+        assert_eq!(0, a.rank(3));
+        assert_eq!(1, a.rank(4));
+        assert_eq!(1, a.rank(127));
+        assert_eq!(2, a.rank(128));
+        assert_eq!(3, a.rank(129));
+        assert_eq!(4, a.rank(255));
+        assert_eq!(5, a.rank(256));
+        assert_eq!(5, a.rank(10_000));
+    }
+
+    #[test]
+    fn test_next_after_prev_before() {
+        let a = index(&[3, 127, 128, 255]);
+        assert_eq!(Some(3), a.next_after(0));
+        assert_eq!(Some(127), a.next_after(3));
+        assert_eq!(Some(128), a.next_after(127));
+        assert_eq!(Some(255), a.next_after(128));
+        assert_eq!(None, a.next_after(255));
+        assert_eq!(None, a.next_after(1000));
+
+        assert_eq!(None, a.prev_before(3));
+        assert_eq!(Some(3), a.prev_before(127));
+        assert_eq!(Some(127), a.prev_before(128));
+        assert_eq!(Some(128), a.prev_before(129));
+        assert_eq!(Some(128), a.prev_before(255));
+        assert_eq!(Some(255), a.prev_before(1000));
+    }
+
+    #[test]
+    fn test_single() {
+        assert_eq!(None, Index::empty().single());
+        assert_eq!(Some(127), index(&[127]).single());
+        assert_eq!(Some(128), index(&[128]).single());
+        assert_eq!(None, index(&[127, 128]).single());
+        assert_eq!(None, index(&[5, 6]).single());
+        assert_eq!(Some(300), DynBitIndex::from_range(300..=300).single());
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(0, Index::empty().ranges().count());
+        let a = Index::from_ranges(vec![3..=7, 12..=12, 40..=99, 120..=135, 255..=255]);
+        assert_eq!(vec![3..=7, 12..=12, 40..=99, 120..=135, 255..=255], a.ranges().collect::<Vec<_>>());
+        assert_eq!(vec![0..=255], Index::from_range(..).ranges().collect::<Vec<_>>());
+        assert_eq!(vec![127..=127, 129..=129], index(&[127, 129]).ranges().collect::<Vec<_>>());
+        assert_eq!(vec![0..=127], DynBitIndex::from_range(0..128).ranges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("{}", Index::empty().to_string());
+        let a = Index::from_ranges(vec![3..=7, 12..=12, 40..=99]);
+        assert_eq!("{3..=7, 12, 40..=99}", a.to_string());
+        assert_eq!("{3..=7, 12, 40..=99}", format!("{:?}", a));
+        assert_eq!("{127..=128}", index(&[127, 128]).to_string());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let a = Index::from_ranges(vec![3..=7, 12..=12, 40..=99, 127..=200]);
+        assert_eq!(Ok(a), a.to_string().parse());
+        assert_eq!(Ok(Index::empty()), "{ }".parse());
+        assert_eq!(Ok(index(&[1, 2, 3])), " { 1..=2 , 3 } ".parse());
+        assert_eq!(Err(ParseBitIndexError::Syntax("1..3".into())), "{1..3}".parse::<Index>());
+        assert_eq!(Err(ParseBitIndexError::Syntax("1, 2".into())), "1, 2".parse::<Index>());
+        assert_eq!(Err(ParseBitIndexError::Capacity(CapacityError { value: 300, capacity: 256 })), "{300}".parse::<Index>());
+
+        let d = DynBitIndex::from_ranges(vec![3..=7, 1000..=1024]);
+        assert_eq!(Ok(d.clone()), d.to_string().parse());
+        let huge = CapacityError { value: usize::MAX, capacity: DynBitIndex::PARSE_CAPACITY };
+        assert_eq!(Err(ParseBitIndexError::Capacity(huge)), "{18446744073709551615}".parse::<DynBitIndex>());
+        assert_eq!(Err(ParseBitIndexError::Capacity(huge)), "{0, 5..=18446744073709551615}".parse::<DynBitIndex>());
+        assert_eq!(Err(ParseBitIndexError::Syntax("9..=3".into())), "{9..=3}".parse::<DynBitIndex>());
+    }
+
+    #[test]
+    fn test_double_ended_iter() {
+        let a = index(&[0, 5, 127, 128, 200, 255]);
+        assert_eq!(6, a.iter().len());
+        assert_eq!(vec![255, 200, 128, 127, 5, 0], a.iter().rev().collect::<Vec<_>>());
+
+        let mut i = a.iter();
+        assert_eq!(Some(0), i.next());
+        assert_eq!(Some(255), i.next_back());
+        assert_eq!(Some(5), i.next());
+        assert_eq!(Some(200), i.next_back());
+        assert_eq!(2, i.len());
+        assert_eq!(Some(128), i.next_back());
+        assert_eq!(Some(127), i.next_back());
+        assert_eq!(None, i.next());
+        assert_eq!(None, i.next_back());
+
+        let mut i = index(&[127, 128]).into_iter();
+        assert_eq!(Some(128), i.next_back());
+        assert_eq!(Some(127), i.next_back());
+        assert_eq!(None, i.next());
+    }
+
+    #[test]
+    fn test_collect_and_extend() {
+        let a = vec![255, 3, 128, 3].into_iter().collect::<Index>();
+        assert_eq!(index(&[3, 128, 255]), a);
+        let mut b = Index::default();
+        b.extend(a);
+        b.extend(vec![4]);
+        assert_eq!(index(&[3, 4, 128, 255]), b);
+
+        let mut total = 0;
+        for v in &b {
+            total += v;
+        }
+        assert_eq!(390, total);
+        assert_eq!(vec![3, 4, 128, 255], b.into_iter().collect::<Vec<_>>());
+
+        let d = vec![1000, 1].into_iter().collect::<DynBitIndex>();
+        assert_eq!(vec![1, 1000], (&d).into_iter().collect::<Vec<_>>());
+        assert_eq!(vec![1000, 1], d.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_hash_key() {
+        use std::collections::HashMap;
+
+        let mut memo: HashMap<Index, usize> = HashMap::new();
+        memo.insert(index(&[1, 200]), 1);
+        assert_eq!(Some(&1), memo.get(&index(&[200, 1])));
+
+        let mut memo: HashMap<DynBitIndex, usize> = HashMap::new();
+        memo.insert(DynBitIndex::with_capacity(1024), 1);
+        let mut d = DynBitIndex::with_capacity(1024);
+        d += 5;
+        memo.insert(d, 2);
+        assert_eq!(Some(&1), memo.get(&DynBitIndex::empty()));
+        assert_eq!(Some(&2), memo.get(&DynBitIndex::from_range(5..=5)));
+    }
+
+    #[test]
+    fn test_ord() {
+        let sets = vec![
+            index(&[]),
+            index(&[0]),
+            index(&[0, 1]),
+            index(&[0, 1, 255]),
+            index(&[0, 2]),
+            index(&[0, 127]),
+            index(&[0, 127, 128]),
+            index(&[0, 128]),
+            index(&[1]),
+            index(&[127, 128]),
+            index(&[128]),
+            index(&[255]),
+        ];
+        for a in &sets {
+            for b in &sets {
+                assert_eq!(a.iter().cmp(b.iter()), a.cmp(b), "{} <=> {}", a, b);
+            }
+        }
+        let mut shuffled = sets.clone();
+        shuffled.reverse();
+        shuffled.sort();
+        assert_eq!(sets, shuffled);
+
+        assert!(DynBitIndex::from_range(1..=1) > DynBitIndex::from_range(0..=1000));
+        assert_eq!(Ordering::Equal, DynBitIndex::with_capacity(512).cmp(&DynBitIndex::empty()));
     }
 }
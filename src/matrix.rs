@@ -0,0 +1,603 @@
+//! A dense boolean matrix stored as one bit set per row, and a bipartite matching solver over it.
+//!
+//! Row `r` containing column `c` reads as "`r` may be assigned to `c`".
+
+use std::fmt::{Debug, Formatter};
+use std::iter::FromIterator;
+
+use crate::{fmt_ranges, kernels, pos, select, words, Bits, Ranges};
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct BitMatrix {
+    rows: usize,
+    columns: usize,
+    stride: usize,
+    words: Vec<u128>,
+}
+
+impl BitMatrix {
+    /// A `rows` by `columns` matrix with no bits set.
+    pub fn new(rows: usize, columns: usize) -> Self {
+        let stride = words(columns);
+        Self {
+            rows,
+            columns,
+            stride,
+            words: vec![0u128; rows * stride],
+        }
+    }
+
+    /// A `rows` by `columns` matrix with every bit set.
+    pub fn full(rows: usize, columns: usize) -> Self {
+        let mut m = Self::new(rows, columns);
+        if columns > 0 {
+            for r in 0..rows {
+                crate::fill_range(m.row_words_mut(r), 0, columns - 1);
+            }
+        }
+        m
+    }
+
+    /// Builds a matrix from the members of each row.
+    ///
+    /// # Panics
+    ///
+    /// If a member is not less than `columns`.
+    pub fn from_rows<R: IntoIterator<Item=usize>, I: IntoIterator<Item=R>>(columns: usize, rows: I) -> Self {
+        let rows = rows.into_iter().collect::<Vec<_>>();
+        let mut m = Self::new(rows.len(), columns);
+        for (r, row) in rows.into_iter().enumerate() {
+            for c in row {
+                m.insert(r, c);
+            }
+        }
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    fn check(&self, r: usize, c: usize) {
+        assert!(r < self.rows && c < self.columns,
+                "position ({}, {}) is out of range for a {}x{} matrix", r, c, self.rows, self.columns);
+    }
+
+    fn row_words(&self, r: usize) -> &[u128] {
+        &self.words[r * self.stride..(r + 1) * self.stride]
+    }
+
+    fn row_words_mut(&mut self, r: usize) -> &mut [u128] {
+        &mut self.words[r * self.stride..(r + 1) * self.stride]
+    }
+
+    pub fn contains(&self, r: usize, c: usize) -> bool {
+        self.check(r, c);
+        let (i, bit) = pos(c);
+        self.row_words(r)[i] & bit != 0
+    }
+
+    /// Sets the bit at `(r, c)`, returning whether it was previously clear.
+    pub fn insert(&mut self, r: usize, c: usize) -> bool {
+        self.check(r, c);
+        let (i, bit) = pos(c);
+        let w = &mut self.row_words_mut(r)[i];
+        let was_clear = *w & bit == 0;
+        *w |= bit;
+        was_clear
+    }
+
+    /// Clears the bit at `(r, c)`, returning whether it was previously set.
+    pub fn remove(&mut self, r: usize, c: usize) -> bool {
+        self.check(r, c);
+        let (i, bit) = pos(c);
+        let w = &mut self.row_words_mut(r)[i];
+        let was_set = *w & bit != 0;
+        *w &= !bit;
+        was_set
+    }
+
+    /// Clears every bit in row `r` whose column fails `keep`.
+    pub fn retain_row<F: FnMut(usize) -> bool>(&mut self, r: usize, mut keep: F) {
+        for c in Bits::new(self.row_words(r)).collect::<Vec<_>>() {
+            if !keep(c) {
+                self.remove(r, c);
+            }
+        }
+    }
+
+    pub fn row(&self, r: usize) -> Row<'_> {
+        assert!(r < self.rows, "row {} is out of range for a matrix with {} rows", r, self.rows);
+        Row(self.row_words(r))
+    }
+
+    pub fn column(&self, c: usize) -> Column<'_> {
+        assert!(c < self.columns, "column {} is out of range for a matrix with {} columns", c, self.columns);
+        Column { matrix: self, column: c }
+    }
+
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item=Row<'_>> + '_ {
+        (0..self.rows).map(move |r| Row(self.row_words(r)))
+    }
+
+    /// The number of bits set in each row.
+    pub fn row_counts(&self) -> Vec<usize> {
+        self.iter_rows().map(|r| r.len()).collect()
+    }
+
+    /// The number of bits set in each column.
+    pub fn column_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.columns];
+        for row in self.iter_rows() {
+            row.iter().for_each(|c| counts[c] += 1);
+        }
+        counts
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::new(self.columns, self.rows);
+        for (r, row) in self.iter_rows().enumerate() {
+            for c in row.iter() {
+                t.insert(c, r);
+            }
+        }
+        t
+    }
+
+    /// A maximum matching found with Hopcroft–Karp: the column assigned to each row, if any.
+    pub fn maximum_matching(&self) -> Vec<Option<usize>> {
+        let mut hk = HopcroftKarp::new(self);
+        while hk.layer() {
+            for r in 0..self.rows {
+                if hk.row_match[r].is_none() {
+                    hk.augment(r);
+                }
+            }
+        }
+        hk.row_match
+    }
+
+    /// Assigns every row a distinct column, if possible.
+    ///
+    /// The assignment of a row is forced when it is the same in every matching that covers all rows.
+    pub fn assign(&self) -> Assignment {
+        let matching = self.maximum_matching();
+        let unmatched = matching.iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_none())
+            .map(|(r, _)| r)
+            .collect::<Vec<_>>();
+        if !unmatched.is_empty() {
+            return Assignment::Infeasible { unmatched };
+        }
+        let matching = matching.into_iter().map(Option::unwrap).collect::<Vec<_>>();
+        let forced = self.forced(&matching);
+        if forced.iter().all(Option::is_some) {
+            Assignment::Unique(matching)
+        } else {
+            Assignment::Ambiguous { forced, matching }
+        }
+    }
+
+    /// Rows whose matched column can not be swapped for another without uncovering some row.
+    ///
+    /// A row can move off its column either around an alternating cycle, or along an alternating path
+    /// that ends at a column no row is matched to.
+    fn forced(&self, matching: &[usize]) -> Vec<Option<usize>> {
+        let mut owner = vec![None; self.columns];
+        for (r, c) in matching.iter().enumerate() {
+            owner[*c] = Some(r);
+        }
+        let owner = &owner;
+        // Row `r` can take column `c` from the row that owns it.
+        let successors = |r: usize| self.row(r).iter()
+            .filter(move |c| *c != matching[r])
+            .map(move |c| owner[c]);
+
+        let mut free = vec![false; self.rows];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for r in 0..self.rows {
+                if !free[r] && successors(r).any(|o| o.is_none_or(|o| free[o])) {
+                    free[r] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        let components = strongly_connected(self.rows, |r| successors(r).flatten().collect());
+        let mut sizes = vec![0; self.rows];
+        components.iter().for_each(|c| sizes[*c] += 1);
+
+        matching.iter()
+            .enumerate()
+            .map(|(r, c)| if free[r] || sizes[components[r]] > 1 { None } else { Some(*c) })
+            .collect()
+    }
+}
+
+/// The outcome of [`BitMatrix::assign`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Assignment {
+    /// Exactly one way to assign every row a distinct column: the column of each row.
+    Unique(Vec<usize>),
+    /// Several ways; `forced` has the column of each row that is the same in all of them, and
+    /// `matching` is one of them.
+    Ambiguous { forced: Vec<Option<usize>>, matching: Vec<usize> },
+    /// No way; `unmatched` are the rows left without a column by a maximum matching.
+    Infeasible { unmatched: Vec<usize> },
+}
+
+struct HopcroftKarp<'a> {
+    matrix: &'a BitMatrix,
+    row_match: Vec<Option<usize>>,
+    column_match: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    limit: usize,
+}
+
+impl<'a> HopcroftKarp<'a> {
+    fn new(matrix: &'a BitMatrix) -> Self {
+        Self {
+            matrix,
+            row_match: vec![None; matrix.rows],
+            column_match: vec![None; matrix.columns],
+            depth: vec![usize::MAX; matrix.rows],
+            limit: usize::MAX,
+        }
+    }
+
+    /// Layers rows by the length of the shortest alternating path from an unmatched row, returning
+    /// whether any path reaches an unmatched column.
+    fn layer(&mut self) -> bool {
+        let mut queue = std::collections::VecDeque::new();
+        for r in 0..self.matrix.rows {
+            if self.row_match[r].is_none() {
+                self.depth[r] = 0;
+                queue.push_back(r);
+            } else {
+                self.depth[r] = usize::MAX;
+            }
+        }
+        self.limit = usize::MAX;
+        while let Some(r) = queue.pop_front() {
+            if self.depth[r] >= self.limit {
+                continue;
+            }
+            for c in self.matrix.row(r).iter() {
+                match self.column_match[c] {
+                    None => self.limit = self.limit.min(self.depth[r]),
+                    Some(next) if self.depth[next] == usize::MAX => {
+                        self.depth[next] = self.depth[r] + 1;
+                        queue.push_back(next);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        self.limit != usize::MAX
+    }
+
+    /// Flips a shortest alternating path from `r` to an unmatched column, if one remains.
+    fn augment(&mut self, r: usize) -> bool {
+        for c in self.matrix.row(r).iter() {
+            let found = match self.column_match[c] {
+                None => self.depth[r] == self.limit,
+                Some(next) => self.depth[next] == self.depth[r] + 1 && self.augment(next),
+            };
+            if found {
+                self.row_match[r] = Some(c);
+                self.column_match[c] = Some(r);
+                return true;
+            }
+        }
+        self.depth[r] = usize::MAX;
+        false
+    }
+}
+
+/// Tarjan's algorithm: the component of each of `n` vertices, numbered in reverse topological order.
+fn strongly_connected<F: Fn(usize) -> Vec<usize>>(n: usize, successors: F) -> Vec<usize> {
+    struct State {
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        component: Vec<usize>,
+        next_index: usize,
+        next_component: usize,
+    }
+
+    fn visit<F: Fn(usize) -> Vec<usize>>(v: usize, successors: &F, s: &mut State) {
+        s.index[v] = Some(s.next_index);
+        s.low[v] = s.next_index;
+        s.next_index += 1;
+        s.stack.push(v);
+        s.on_stack[v] = true;
+        for w in successors(v) {
+            match s.index[w] {
+                None => {
+                    visit(w, successors, s);
+                    s.low[v] = s.low[v].min(s.low[w]);
+                }
+                Some(i) if s.on_stack[w] => s.low[v] = s.low[v].min(i),
+                Some(_) => {}
+            }
+        }
+        if Some(s.low[v]) == s.index[v] {
+            while let Some(w) = s.stack.pop() {
+                s.on_stack[w] = false;
+                s.component[w] = s.next_component;
+                if w == v {
+                    break;
+                }
+            }
+            s.next_component += 1;
+        }
+    }
+
+    let mut s = State {
+        index: vec![None; n],
+        low: vec![0; n],
+        on_stack: vec![false; n],
+        stack: Vec::new(),
+        component: vec![0; n],
+        next_index: 0,
+        next_component: 0,
+    };
+    for v in 0..n {
+        if s.index[v].is_none() {
+            visit(v, &successors, &mut s);
+        }
+    }
+    s.component
+}
+
+impl Debug for BitMatrix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+/// A borrowed row of a [`BitMatrix`]: the columns set in it.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Row<'a>(&'a [u128]);
+
+impl<'a> Row<'a> {
+    pub fn contains(&self, c: usize) -> bool {
+        let (i, bit) = pos(c);
+        self.0.get(i).is_some_and(|w| w & bit != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        kernels::count_ones(self.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        kernels::is_zero(self.0)
+    }
+
+    pub fn iter(&self) -> Bits<'a> {
+        Bits::new(self.0)
+    }
+
+    /// The maximal runs of consecutive members, in ascending order.
+    pub fn ranges(&self) -> Ranges<'a> {
+        Ranges::new(self.0)
+    }
+
+    /// The only member, if there is exactly one.
+    pub fn single(&self) -> Option<usize> {
+        select::single(self.0)
+    }
+}
+
+impl<'a> IntoIterator for Row<'a> {
+    type Item = usize;
+    type IntoIter = Bits<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Debug for Row<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_ranges(self.ranges(), f)
+    }
+}
+
+/// A borrowed column of a [`BitMatrix`]: the rows in which it is set.
+#[derive(Copy, Clone)]
+pub struct Column<'a> {
+    matrix: &'a BitMatrix,
+    column: usize,
+}
+
+impl<'a> Column<'a> {
+    pub fn contains(&self, r: usize) -> bool {
+        r < self.matrix.rows && self.matrix.contains(r, self.column)
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=usize> + 'a {
+        let Column { matrix, column } = *self;
+        (0..matrix.rows).filter(move |r| matrix.contains(*r, column))
+    }
+
+    /// The only member, if there is exactly one.
+    pub fn single(&self) -> Option<usize> {
+        let mut rows = self.iter();
+        match (rows.next(), rows.next()) {
+            (Some(r), None) => Some(r),
+            _ => None,
+        }
+    }
+}
+
+impl Debug for Column<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<R: IntoIterator<Item=usize>> FromIterator<R> for BitMatrix {
+    /// Collects rows of column numbers, sizing the matrix to fit the largest.
+    fn from_iter<I: IntoIterator<Item=R>>(iter: I) -> Self {
+        let rows = iter.into_iter()
+            .map(|r| r.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let columns = rows.iter().flatten().max().map_or(0, |c| c + 1);
+        Self::from_rows(columns, rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn matrix(rows: &[&[usize]], columns: usize) -> BitMatrix {
+        BitMatrix::from_rows(columns, rows.iter().map(|r| r.iter().copied()))
+    }
+
+    /// Every way of giving each row a distinct column, by exhaustive search.
+    fn all_matchings(m: &BitMatrix) -> Vec<Vec<usize>> {
+        fn extend(m: &BitMatrix, prefix: &mut Vec<usize>, out: &mut Vec<Vec<usize>>) {
+            if prefix.len() == m.rows() {
+                out.push(prefix.clone());
+                return;
+            }
+            for c in m.row(prefix.len()).iter() {
+                if !prefix.contains(&c) {
+                    prefix.push(c);
+                    extend(m, prefix, out);
+                    prefix.pop();
+                }
+            }
+        }
+        let mut out = Vec::new();
+        extend(m, &mut Vec::new(), &mut out);
+        out
+    }
+
+    #[test]
+    fn test_views() {
+        let m = matrix(&[&[0, 2], &[1], &[0, 1, 2]], 3);
+        assert_eq!(vec![0, 2], m.row(0).iter().collect::<Vec<_>>());
+        assert_eq!(vec![0, 2], m.column(0).iter().collect::<Vec<_>>());
+        assert_eq!(vec![2, 1, 3], m.row_counts());
+        assert_eq!(vec![2, 2, 2], m.column_counts());
+        assert_eq!(Some(1), m.row(1).single());
+        assert_eq!(None, m.column(1).single());
+        assert_eq!(matrix(&[&[0, 2], &[1, 2], &[0, 2]], 3), m.transpose());
+        assert_eq!(m, m.transpose().transpose());
+        assert_eq!("[{0, 2}, {1}, {0..=2}]", format!("{:?}", m));
+    }
+
+    #[test]
+    fn test_wide_rows() {
+        let mut m = BitMatrix::full(2, 300);
+        assert_eq!(vec![300, 300], m.row_counts());
+        m.retain_row(1, |c| c % 100 == 0);
+        assert_eq!(vec![0, 100, 200], m.row(1).iter().collect::<Vec<_>>());
+        assert!(m.remove(0, 299));
+        assert!(!m.contains(0, 299));
+        assert_eq!(vec![0, 1], m.column(200).iter().collect::<Vec<_>>());
+        assert!(m.column(299).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "position (0, 3) is out of range for a 2x3 matrix")]
+    fn test_insert_out_of_range() {
+        BitMatrix::new(2, 3).insert(0, 3);
+    }
+
+    #[test]
+    fn test_unique() {
+        // The day 16 example, already narrowed down.
+        let m = matrix(&[&[1], &[0, 1], &[0, 1, 2]], 3);
+        assert_eq!(Assignment::Unique(vec![1, 0, 2]), m.assign());
+    }
+
+    #[test]
+    fn test_forced_when_elimination_stalls() {
+        // No row has a single candidate, but rows 0 and 1 between them use up columns 0 and 1.
+        let m = matrix(&[&[0, 1], &[0, 1], &[0, 1, 2], &[2, 3]], 4);
+        match m.assign() {
+            Assignment::Ambiguous { forced, matching } => {
+                assert_eq!(vec![None, None, Some(2), Some(3)], forced);
+                assert!(all_matchings(&m).contains(&matching));
+            }
+            other => panic!("expected an ambiguous assignment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forced_with_spare_columns() {
+        // Column 3 is spare, so row 1 can move onto it and free column 1 for row 0.
+        let m = matrix(&[&[0, 1], &[1, 3], &[2]], 4);
+        match m.assign() {
+            Assignment::Ambiguous { forced, .. } => assert_eq!(vec![None, None, Some(2)], forced),
+            other => panic!("expected an ambiguous assignment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_infeasible() {
+        let m = matrix(&[&[0, 1], &[1], &[0, 1], &[2]], 3);
+        match m.assign() {
+            Assignment::Infeasible { unmatched } => assert_eq!(1, unmatched.len()),
+            other => panic!("expected an infeasible assignment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forced_matches_exhaustive_search() {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        for _ in 0..500 {
+            let rows = 1 + next() as usize % 6;
+            let columns = rows + next() as usize % 2;
+            let bits = next();
+            let m = BitMatrix::from_rows(columns, (0..rows)
+                .map(|r| (0..columns).filter(move |c| bits >> (r * columns + c) & 1 != 0)));
+
+            let all = all_matchings(&m);
+            let expected = match all.len() {
+                0 => None,
+                1 => Some(Assignment::Unique(all[0].clone())),
+                _ => Some(Assignment::Ambiguous {
+                    forced: (0..rows)
+                        .map(|r| Some(all[0][r]).filter(|c| all.iter().all(|a| a[r] == *c)))
+                        .collect(),
+                    matching: Vec::new(),
+                }),
+            };
+            match (expected, m.assign()) {
+                (None, Assignment::Infeasible { .. }) => {}
+                (Some(Assignment::Ambiguous { forced: expected, .. }), Assignment::Ambiguous { forced, matching }) => {
+                    assert_eq!(expected, forced, "{:?}", m);
+                    assert!(all.contains(&matching), "{:?}", m);
+                }
+                (expected, actual) => assert_eq!(expected, Some(actual), "{:?}", m),
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Running independent jobs on a few threads while reporting their results in order.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// The message a panic was raised with.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Applies `f` to each item on up to `jobs` threads, handing each result to `each` in the order
+/// of the items, as soon as it and all results before it are done.
+///
+/// A panic in `f` is caught and handed on as `Err` with its message, and the other items still run.
+pub fn map_ordered<T, R, F, E>(items: &[T], jobs: usize, f: F, mut each: E)
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+        E: FnMut(usize, Result<R, String>) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else { break };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .map_err(|payload| panic_message(payload.as_ref()));
+                if sender.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&reported) {
+                each(reported, result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_ordered() {
+        let items = (0..20u64).collect::<Vec<_>>();
+        let mut results = Vec::new();
+        map_ordered(&items, 4, |&n| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        }, |i, r| results.push((i, r.unwrap())));
+        assert_eq!(items.iter().map(|&n| (n as usize, n * n)).collect::<Vec<_>>(), results);
+    }
+
+    #[test]
+    fn test_panics_are_isolated() {
+        let items = [1, 0, 2];
+        let mut results = Vec::new();
+        map_ordered(&items, 2, |&n| {
+            assert!(n != 0, "no zeros");
+            10 / n
+        }, |_, r| results.push(r));
+        assert_eq!(vec![Ok(10), Err("no zeros".to_string()), Ok(5)], results);
+    }
+
+    #[test]
+    fn test_no_items() {
+        map_ordered(&[] as &[u32], 4, |&n| n, |_, _| panic!("no results expected"));
+    }
+}
@@ -0,0 +1,172 @@
+//! `Serialize`/`Deserialize` for the bit indices, enabled by the `serde` feature.
+//!
+//! Human-readable formats get a list of inclusive `[start, end]` runs, e.g. `[[3, 7], [12, 12]]`.
+//! Compact formats get the storage words, each `u128` split into two little-endian `u64`s.
+
+use std::iter::once;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{select, BitIndex, CapacityError, DynBitIndex, Ranges};
+
+fn serialize<S: Serializer>(words: &[u128], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.collect_seq(Ranges::new(words).map(|r| (*r.start(), *r.end())))
+    } else {
+        let len = words.iter().rposition(|v| *v != 0).map_or(0, |i| i + 1);
+        serializer.collect_seq(words[..len].iter()
+            .flat_map(|w| once(*w as u64).chain(once((*w >> 64) as u64))))
+    }
+}
+
+enum Encoded {
+    Ranges(Vec<(usize, usize)>),
+    Words(Vec<u128>),
+}
+
+fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Encoded, D::Error> {
+    if deserializer.is_human_readable() {
+        let ranges = Vec::<(usize, usize)>::deserialize(deserializer)?;
+        if let Some((start, end)) = ranges.iter().find(|(start, end)| start > end) {
+            return Err(D::Error::custom(format_args!("range start {} is after its end {}", start, end)));
+        }
+        Ok(Encoded::Ranges(ranges))
+    } else {
+        let halves = Vec::<u64>::deserialize(deserializer)?;
+        if halves.len() % 2 != 0 {
+            return Err(D::Error::invalid_length(halves.len(), &"an even number of 64-bit words"));
+        }
+        Ok(Encoded::Words(halves.chunks(2)
+            .map(|w| w[0] as u128 | (w[1] as u128) << 64)
+            .collect()))
+    }
+}
+
+impl<const N: usize> Serialize for BitIndex<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for BitIndex<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserialize(deserializer)? {
+            Encoded::Ranges(ranges) => BitIndex::try_from_ranges(ranges.into_iter().map(|(start, end)| start..=end))
+                .map_err(D::Error::custom),
+            Encoded::Words(words) => {
+                if words.iter().skip(N).any(|v| *v != 0) {
+                    let value = select::last(&words).unwrap();
+                    return Err(D::Error::custom(CapacityError { value, capacity: Self::CAPACITY }));
+                }
+                let mut a = [0u128; N];
+                a.iter_mut().zip(words).for_each(|(a, w)| *a = w);
+                Ok(BitIndex(a))
+            }
+        }
+    }
+}
+
+impl Serialize for DynBitIndex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DynBitIndex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserialize(deserializer)? {
+            Encoded::Ranges(ranges) => DynBitIndex::try_from_ranges_within(
+                ranges.into_iter().map(|(start, end)| start..=end), DynBitIndex::PARSE_CAPACITY)
+                .map_err(D::Error::custom),
+            Encoded::Words(mut words) => {
+                let limit = DynBitIndex::PARSE_CAPACITY / 128;
+                if words.iter().skip(limit).any(|v| *v != 0) {
+                    let value = select::last(&words).unwrap();
+                    return Err(D::Error::custom(CapacityError { value, capacity: DynBitIndex::PARSE_CAPACITY }));
+                }
+                words.truncate(limit);
+                Ok(DynBitIndex(words))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    use crate::{BitIndex, DynBitIndex};
+
+    type Index = BitIndex<2>;
+
+    #[test]
+    fn test_readable() {
+        let a = Index::from_ranges(vec![3..=7, 12..=12, 127..=128]);
+        assert_tokens(&a.readable(), &[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 }, Token::U64(3), Token::U64(7), Token::TupleEnd,
+            Token::Tuple { len: 2 }, Token::U64(12), Token::U64(12), Token::TupleEnd,
+            Token::Tuple { len: 2 }, Token::U64(127), Token::U64(128), Token::TupleEnd,
+            Token::SeqEnd,
+        ]);
+        let d = DynBitIndex::from_ranges(vec![1000..=1001]);
+        assert_tokens(&d.readable(), &[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 }, Token::U64(1000), Token::U64(1001), Token::TupleEnd,
+            Token::SeqEnd,
+        ]);
+    }
+
+    #[test]
+    fn test_compact() {
+        let a = Index::from_ranges(vec![0..=0, 64..=64, 128..=128]);
+        assert_tokens(&a.compact(), &[
+            Token::Seq { len: None },
+            Token::U64(1), Token::U64(1), Token::U64(1), Token::U64(0),
+            Token::SeqEnd,
+        ]);
+        assert_tokens(&Index::empty().compact(), &[
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+        ]);
+    }
+
+    #[test]
+    fn test_rejects_out_of_capacity() {
+        assert_de_tokens_error::<serde_test::Readable<Index>>(&[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 }, Token::U64(250), Token::U64(256), Token::TupleEnd,
+            Token::SeqEnd,
+        ], "value 256 is out of range for a bit index with capacity 256");
+        assert_de_tokens_error::<serde_test::Compact<Index>>(&[
+            Token::Seq { len: None },
+            Token::U64(0), Token::U64(0), Token::U64(0), Token::U64(0), Token::U64(0), Token::U64(1),
+            Token::SeqEnd,
+        ], "value 320 is out of range for a bit index with capacity 256");
+        assert_de_tokens_error::<serde_test::Readable<DynBitIndex>>(&[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 }, Token::U64(5), Token::U64(u64::MAX), Token::TupleEnd,
+            Token::SeqEnd,
+        ], "value 18446744073709551615 is out of range for a bit index with capacity 16777216");
+        let mut words = vec![Token::Seq { len: None }];
+        words.extend(std::iter::repeat_n(Token::U64(0), 2 * DynBitIndex::PARSE_CAPACITY / 128));
+        words.extend([Token::U64(1), Token::U64(0), Token::SeqEnd]);
+        assert_de_tokens_error::<serde_test::Compact<DynBitIndex>>(&words,
+            "value 16777216 is out of range for a bit index with capacity 16777216");
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        assert_de_tokens_error::<serde_test::Readable<Index>>(&[
+            Token::Seq { len: None },
+            Token::Tuple { len: 2 }, Token::U64(7), Token::U64(3), Token::TupleEnd,
+            Token::SeqEnd,
+        ], "range start 7 is after its end 3");
+        assert_de_tokens_error::<serde_test::Compact<DynBitIndex>>(&[
+            Token::Seq { len: None },
+            Token::U64(1),
+            Token::SeqEnd,
+        ], "invalid length 1, expected an even number of 64-bit words");
+    }
+}
@@ -0,0 +1,219 @@
+//! The shape shared by every day: parse the puzzle input once, then solve each part from it.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use std::process::exit;
+
+use crate::days::Part;
+use crate::input;
+use crate::timing::{Samples, Timing};
+
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Output;
+
+    fn part2(input: &Self::Input) -> Self::Output;
+
+    fn solve(input: &Self::Input, part: Part) -> Self::Output {
+        match part {
+            Part::A => Self::part1(input),
+            Part::B => Self::part2(input),
+        }
+    }
+}
+
+/// A piece of the input that does not have the expected form.
+///
+/// `found` must be a slice of the input being parsed, so that [`ParseError::new`] can tell where
+/// it is. An empty slice points at the place something is missing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unexpected<'a> {
+    pub found: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Unexpected<'a> {
+    pub fn new<E: Into<String>>(found: &'a str, expected: E) -> Self {
+        Self { found, expected: expected.into() }
+    }
+
+    /// Something missing at the end of `s`.
+    pub fn end_of<E: Into<String>>(s: &'a str, expected: E) -> Self {
+        Self::new(&s[s.len()..], expected)
+    }
+}
+
+/// Puzzle input that does not have the expected form, and where.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u32,
+    /// The line the problem is on, counting from one.
+    pub line: usize,
+    /// The character the problem starts at, counting from one.
+    pub column: usize,
+    /// The offending text, empty if something is missing.
+    pub text: String,
+    pub expected: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// Locates `unexpected` in `input`, which it must be a slice of.
+    pub fn new(day: u32, input: &str, unexpected: Unexpected<'_>) -> Self {
+        let offset = (unexpected.found.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "{:?} is not part of the input", unexpected.found);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        Self {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: unexpected.found.to_string(),
+            expected: unexpected.expected,
+            source_line: input[line_start..line_end].trim_end_matches('\r').to_string(),
+        }
+    }
+
+    fn found(&self) -> String {
+        if self.text.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("{:?}", self.text)
+        }
+    }
+
+    /// A compiler style report of the error in the input read from `source`, with a caret under
+    /// the offending text.
+    pub fn diagnostic(&self, source: &dyn Display) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let indent = self.source_line.chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let width = self.text.lines().next().map_or(0, |t| t.chars().count()).max(1);
+        format!("error[day {:02}]: expected {}, found {}\n\
+                 {margin}--> {}:{}:{}\n\
+                 {margin} |\n\
+                 {} | {}\n\
+                 {margin} | {}{} expected {}",
+                self.day, self.expected, self.found(),
+                source, self.line, self.column,
+                number, self.source_line,
+                indent, "^".repeat(width), self.expected,
+                margin = margin)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found())
+    }
+}
+
+impl Error for ParseError {}
+
+/// The answer to a part, or why input that parsed has none, e.g. when no two entries add up to
+/// 2020.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Answer<T> {
+    Found(T),
+    None(&'static str),
+}
+
+impl<T> Answer<T> {
+    /// The answer in `option`, or `None(why)`.
+    pub fn from_option(option: Option<T>, why: &'static str) -> Self {
+        option.map_or(Answer::None(why), Answer::Found)
+    }
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Found(answer) => answer.fmt(f),
+            Answer::None(why) => write!(f, "no answer: {}", why),
+        }
+    }
+}
+
+/// Parses each line of `input` with `f`.
+pub fn parse_lines<'a, T, F: FnMut(&'a str) -> Result<T, Unexpected<'a>>>(input: &'a str, f: F) -> Result<Vec<T>, Unexpected<'a>> {
+    input.lines()
+        .map(f)
+        .collect()
+}
+
+/// Parses `s` as a number.
+pub fn number<T: std::str::FromStr>(s: &str) -> Result<T, Unexpected<'_>> {
+    let trimmed = s.trim();
+    trimmed.parse().map_err(|_| Unexpected::new(trimmed, "a number"))
+}
+
+/// Parses one part of `S` from raw input and renders the answer.
+pub fn run<S: Solution>(input: &str, part: Part) -> Result<String, ParseError> {
+    S::parse(input).map(|input| S::solve(&input, part).to_string())
+}
+
+/// Parses and solves one part of `S` `runs` times each, timing the two separately.
+pub fn time<S: Solution>(input: &str, part: Part, runs: usize) -> Result<Timing, ParseError> {
+    let (parse, parsed) = Samples::measure(runs, || S::parse(input));
+    let parsed = parsed?;
+    let (solve, answer) = Samples::measure(runs, || S::solve(&parsed, part));
+    Ok(Timing { parse, solve, answer: answer.to_string() })
+}
+
+/// The body of a per-day binary: prints the answer to one part of `S` for the input named on the
+/// command line, or the error and exits.
+pub fn main<S: Solution>(part: Part) {
+    let input = input::from_args(S::DAY);
+    match run::<S>(&input.text, part) {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => {
+            eprintln!("{}", e.diagnostic(&input.source));
+            exit(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "12\n3x4\n5\n";
+        let e = ParseError::new(9, input, Unexpected::new(&input[4..5], "a digit"));
+        assert_eq!((9, 2, 2, "x"), (e.day, e.line, e.column, e.text.as_str()));
+        assert_eq!("line 2, column 2: expected a digit, found \"x\"", e.to_string());
+
+        let e = ParseError::new(9, input, Unexpected::end_of(&input[..2], "a comma"));
+        assert_eq!((1, 3, ""), (e.line, e.column, e.text.as_str()));
+        assert_eq!("line 1, column 3: expected a comma, found nothing", e.to_string());
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!("42", Answer::from_option(Some(42), "none at all").to_string());
+        assert_eq!("no answer: none at all", Answer::<u32>::from_option(None, "none at all").to_string());
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "1721\n979\n36x6\n";
+        let e = ParseError::new(1, input, number::<u32>(&input[9..13]).unwrap_err());
+        assert_eq!("error[day 01]: expected a number, found \"36x6\"
+ --> aoc01.txt:3:1
+  |
+3 | 36x6
+  | ^^^^ expected a number", e.diagnostic(&"aoc01.txt"));
+    }
+}
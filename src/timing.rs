@@ -0,0 +1,208 @@
+//! Timing parse and solve for each day and part, and reporting the results.
+//!
+//! Each solver is run a number of times; parse and solve are timed separately, and the median
+//! and fastest run of each are reported as a table or as JSON for comparing across commits.
+
+use std::fmt::{Display, Formatter, Write};
+use std::time::{Duration, Instant};
+
+use crate::days::Solver;
+
+/// The durations of repeated runs of one step.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    pub fn new(mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+        Self(durations)
+    }
+
+    /// Times `runs` calls of `f`, returning what the last one returned.
+    pub fn measure<T, F: FnMut() -> T>(runs: usize, mut f: F) -> (Self, T) {
+        assert!(runs > 0, "at least one run is needed");
+        let mut durations = Vec::with_capacity(runs);
+        let mut result = None;
+        for _ in 0..runs {
+            let start = Instant::now();
+            let value = f();
+            durations.push(start.elapsed());
+            result = Some(value);
+        }
+        (Self::new(durations), result.unwrap())
+    }
+
+    pub fn runs(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.first().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let n = self.0.len();
+        match n {
+            0 => Duration::default(),
+            _ if n % 2 == 1 => self.0[n / 2],
+            _ => (self.0[n / 2 - 1] + self.0[n / 2]) / 2,
+        }
+    }
+}
+
+/// The parse and solve times of one solver, and the answer it gave.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timing {
+    pub parse: Samples,
+    pub solve: Samples,
+    pub answer: String,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse.median() + self.solve.median()
+    }
+}
+
+/// Formats a duration with three significant-ish digits in a unit that suits it.
+pub struct Short(pub Duration);
+
+impl Display for Short {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let s = if nanos < 1e3 {
+            format!("{}ns", nanos)
+        } else if nanos < 1e6 {
+            format!("{:.1}µs", nanos / 1e3)
+        } else if nanos < 1e9 {
+            format!("{:.1}ms", nanos / 1e6)
+        } else {
+            format!("{:.2}s", nanos / 1e9)
+        };
+        f.pad(&s)
+    }
+}
+
+/// The timings of a run over several solvers.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    pub runs: usize,
+    entries: Vec<(String, u32, String, Timing)>,
+}
+
+impl Report {
+    pub fn new(runs: usize) -> Self {
+        Self { runs, entries: Vec::new() }
+    }
+
+    pub fn add(&mut self, solver: &Solver, timing: Timing) {
+        self.entries.push((solver.name(), solver.day, solver.part.to_string(), timing));
+    }
+
+    pub fn header() -> String {
+        format!("{:<12} {:>10} {:>10} {:>10} {:>10}  answer", "solver", "parse", "parse min", "solve", "solve min")
+    }
+
+    /// The table row of one timing: medians and minimums of parse and solve.
+    pub fn row(name: &str, timing: &Timing) -> String {
+        format!("{:<12} {:>10} {:>10} {:>10} {:>10}  {}", name,
+                Short(timing.parse.median()), Short(timing.parse.min()),
+                Short(timing.solve.median()), Short(timing.solve.min()),
+                timing.answer)
+    }
+
+    /// The sum of the median parse and solve times of every entry.
+    pub fn total(&self) -> Duration {
+        self.entries.iter().map(|(_, _, _, t)| t.total()).sum()
+    }
+
+    /// The report as JSON, durations in nanoseconds.
+    pub fn to_json(&self) -> String {
+        let mut json = format!("{{\n  \"runs\": {},\n  \"total_ns\": {},\n  \"results\": [", self.runs, self.total().as_nanos());
+        for (i, (name, day, part, timing)) in self.entries.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(json, "{}\n    {{\"name\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \
+                          \"parse\": {{\"median_ns\": {}, \"min_ns\": {}}}, \
+                          \"solve\": {{\"median_ns\": {}, \"min_ns\": {}}}}}",
+                   separator, quote(name), day, quote(part), quote(&timing.answer),
+                   timing.parse.median().as_nanos(), timing.parse.min().as_nanos(),
+                   timing.solve.median().as_nanos(), timing.solve.min().as_nanos()).unwrap();
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+/// `s` as a JSON string.
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn ms(ms: &[u64]) -> Samples {
+        Samples::new(ms.iter().map(|&ms| Duration::from_millis(ms)).collect())
+    }
+
+    #[test]
+    fn test_median_and_min() {
+        let samples = ms(&[5, 1, 3]);
+        assert_eq!(Duration::from_millis(3), samples.median());
+        assert_eq!(Duration::from_millis(1), samples.min());
+        assert_eq!(Duration::from_millis(4), ms(&[8, 2, 3, 5]).median());
+        assert_eq!(Duration::default(), ms(&[]).median());
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let (samples, last) = Samples::measure(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(3, samples.runs());
+        assert_eq!(3, last);
+    }
+
+    #[test]
+    fn test_short() {
+        assert_eq!("950ns", Short(Duration::from_nanos(950)).to_string());
+        assert_eq!("12.3µs", Short(Duration::from_nanos(12_345)).to_string());
+        assert_eq!("4.0ms", Short(Duration::from_micros(4_000)).to_string());
+        assert_eq!("2.50s", Short(Duration::from_millis(2_500)).to_string());
+        assert_eq!("  1.0ms", format!("{:>7}", Short(Duration::from_millis(1))));
+    }
+
+    #[test]
+    fn test_json() {
+        let solver = days::find(1, Some(days::Part::A), None).next().unwrap();
+        let mut report = Report::new(2);
+        report.add(solver, Timing { parse: ms(&[1, 3]), solve: ms(&[4, 4]), answer: "514579".to_string() });
+        assert_eq!("{
+  \"runs\": 2,
+  \"total_ns\": 6000000,
+  \"results\": [
+    {\"name\": \"01a\", \"day\": 1, \"part\": \"a\", \"answer\": \"514579\", \
+\"parse\": {\"median_ns\": 2000000, \"min_ns\": 1000000}, \
+\"solve\": {\"median_ns\": 4000000, \"min_ns\": 4000000}}
+  ]
+}
+", report.to_json());
+        assert_eq!("\"a\\\"b\\\\c\\n\\u0001\"", quote("a\"b\\c\n\u{1}"));
+    }
+}
@@ -0,0 +1,42 @@
+//! Runs the `aoc` binary the way a user would and checks what it prints and how it exits.
+
+use std::process::{Command, Output};
+
+use serde_json::{json, Value};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env_remove("AOC_INPUT_DIR")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn gen_without_a_generator_is_a_usage_error() {
+    for args in [&["gen", "25"][..], &["gen", "25", "--size", "10"]] {
+        let output = aoc(args);
+        assert_eq!(Some(2), output.status.code(), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("aoc: no generator for day 25\n"));
+    }
+}
+
+#[test]
+fn gen_prints_input() {
+    let output = aoc(&["gen", "1", "--size", "10"]);
+    assert!(output.status.success());
+    assert_eq!(10, String::from_utf8(output.stdout).unwrap().lines().count());
+}
+
+#[test]
+fn json_to_stdout_is_only_json() {
+    let output = aoc(&["run", "1", "--runs", "1", "--json", "-"]);
+    assert!(output.status.success());
+    let json = serde_json::from_slice::<Value>(&output.stdout).unwrap();
+    assert_eq!(json!(1), json["runs"]);
+    let names = json["results"].as_array().unwrap().iter().map(|r| &r["name"]).collect::<Vec<_>>();
+    assert_eq!(vec![&json!("01a"), &json!("01b")], names);
+    let table = String::from_utf8(output.stderr).unwrap();
+    assert!(table.starts_with("solver "), "{}", table);
+    assert!(table.lines().any(|line| line.starts_with("total ")), "{}", table);
+}
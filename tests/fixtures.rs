@@ -0,0 +1,90 @@
+//! Runs every fixture against the library solvers.
+//!
+//! A fixture is an input `tests/fixtures/dayNN/<name>.txt`. Its expected answers go in the
+//! `[<name>]` table of `tests/fixtures/dayNN/answers.toml`, keyed like the main manifest:
+//!
+//! ```toml
+//! [example]
+//! 01a = 514579
+//! 01b = 241861950
+//! ```
+//!
+//! Only the parts with an expected answer are run, by every solver for them including variants.
+//!
+//! Day 9 has no fixtures: its solvers check each number against the 25 before it, while the
+//! example in the puzzle uses 5, so the example stays with the tests in `src/days/day09`.
+
+use std::path::{Path, PathBuf};
+
+use adventofcode2020::answers::{Answers, Verdict};
+use adventofcode2020::days::{self, Part};
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// The day directories, as `(day, path)`, ordered by day.
+fn days() -> Vec<(u32, PathBuf)> {
+    let mut days = std::fs::read_dir(fixtures_dir()).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let day = name.strip_prefix("day").and_then(|d| d.parse().ok())
+                .unwrap_or_else(|| panic!("{} is not named dayNN", path.display()));
+            (day, path)
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+    days
+}
+
+/// The names of the inputs in `dir`, sorted.
+fn inputs(dir: &Path) -> Vec<String> {
+    let mut names = std::fs::read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_fixtures() {
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for (day, dir) in days() {
+        let answers = Answers::load(dir.join("answers.toml")).unwrap();
+        let names = inputs(&dir);
+        for name in answers.inputs().filter(|name| !names.iter().any(|n| n == name)) {
+            failures.push(format!("day{:02}/answers.toml: [{}] has no {}.txt", day, name, name));
+        }
+        for name in names {
+            let input = std::fs::read_to_string(dir.join(format!("{}.txt", name))).unwrap();
+            let parts = [Part::A, Part::B].iter()
+                .filter(|&&part| answers.expected(&name, day, part).is_some())
+                .collect::<Vec<_>>();
+            if parts.is_empty() {
+                failures.push(format!("day{:02}/{}.txt: no expected answers in answers.toml", day, name));
+            }
+            for &part in parts {
+                let solvers = days::solvers().iter().filter(|s| s.day == day && s.part == part);
+                for solver in solvers {
+                    checked += 1;
+                    let verdict = match solver.solve(&input) {
+                        Ok(answer) => answers.verify(&name, day, part, &answer),
+                        Err(e) => {
+                            failures.push(format!("{} on day{:02}/{}.txt: {}", solver.name(), day, name, e));
+                            continue;
+                        }
+                    };
+                    if verdict != Verdict::Pass {
+                        failures.push(format!("{} on day{:02}/{}.txt: {}", solver.name(), day, name, verdict));
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{} of {} fixture runs failed:\n{}", failures.len(), checked, failures.join("\n"));
+    assert!(checked > 0, "no fixtures found in {}", fixtures_dir().display());
+}